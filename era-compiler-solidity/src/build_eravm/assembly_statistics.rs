@@ -0,0 +1,92 @@
+//!
+//! The EraVM assembly statistics.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The EraVM assembly statistics.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AssemblyStatistics {
+    /// The number of functions.
+    pub functions: usize,
+    /// The number of instructions by category.
+    pub instructions: BTreeMap<String, usize>,
+}
+
+impl AssemblyStatistics {
+    /// The label prefix the code generator emits at the beginning of each function.
+    pub const FUNCTION_BEGIN_LABEL_PREFIX: &'static str = ".func_begin";
+
+    ///
+    /// Counts the functions and the instructions of the code sections of the EraVM assembly.
+    ///
+    pub fn from_assembly(assembly: &str) -> Self {
+        let mut statistics = Self::default();
+        let mut is_code = false;
+        for line in assembly.lines() {
+            let line = line.split(';').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(label) = line.strip_suffix(':') {
+                if label.starts_with(Self::FUNCTION_BEGIN_LABEL_PREFIX) {
+                    statistics.functions += 1;
+                }
+                continue;
+            }
+
+            if let Some(directive) = line.strip_prefix('.') {
+                let mut parts = directive.split_whitespace();
+                match parts.next() {
+                    Some("text") => is_code = true,
+                    Some("data" | "rodata" | "bss") => is_code = false,
+                    Some("section") => {
+                        is_code = parts
+                            .next()
+                            .is_some_and(|name| name.trim_matches('"').starts_with(".text"));
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            if is_code {
+                let mnemonic = line.split_whitespace().next().unwrap_or_default();
+                *statistics
+                    .instructions
+                    .entry(Self::category(mnemonic).to_owned())
+                    .or_default() += 1;
+            }
+        }
+        statistics
+    }
+
+    ///
+    /// Returns the category of the instruction with `mnemonic`.
+    ///
+    /// The modifiers, such as condition codes and the flag setting `!`, are ignored.
+    ///
+    pub fn category(mnemonic: &str) -> &'static str {
+        let stem = mnemonic
+            .split('.')
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches('!');
+        match stem {
+            "add" | "sub" | "mul" | "div" => "arithmetic",
+            "and" | "or" | "xor" | "shl" | "shr" | "rol" | "ror" => "bitwise",
+            "jump" | "call" | "callf" | "calld" | "callm" | "far_call" | "near_call" | "ret"
+            | "retl" | "rev" | "revl" | "revert" | "pnc" | "pncl" | "panic" => "control",
+            "log" | "sload" | "sstore" | "tload" | "tstore" | "event" | "tol1" | "precompile" => {
+                "storage"
+            }
+            "context" => "context",
+            "ptr" => "pointer",
+            stem if stem.starts_with("ld") || stem.starts_with("st") => "memory",
+            _ => "other",
+        }
+    }
+}
@@ -8,6 +8,9 @@ use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::linker::inspection::Inspection as LinkerInspection;
+use crate::verifier::metadata::Metadata as VerifierMetadata;
+
 ///
 /// The Solidity contract build.
 ///
@@ -28,6 +31,18 @@ pub struct Contract {
         HashMap<[u8; era_compiler_common::BYTE_LENGTH_FIELD], String>,
     /// The binary object format.
     pub object_format: era_compiler_common::ObjectFormat,
+    /// Whether the contract was recompiled with `-Oz` due to the size fallback.
+    pub is_size_fallback: bool,
+    /// The source map, with an entry per instruction.
+    pub source_map: Option<String>,
+    /// The unoptimized LLVM IR.
+    pub llvm_ir: Option<String>,
+    /// The optimized LLVM IR.
+    pub llvm_ir_optimized: Option<String>,
+    /// The Yul IR passed to the code generator.
+    pub yul_optimized: Option<String>,
+    /// The compilation statistics.
+    pub statistics: Option<era_solc::StandardJsonOutputContractEraVMStatistics>,
 }
 
 impl Contract {
@@ -50,7 +65,66 @@ impl Contract {
             factory_dependencies,
             factory_dependencies_resolved: HashMap::new(),
             object_format,
+            is_size_fallback: false,
+            source_map: None,
+            llvm_ir: None,
+            llvm_ir_optimized: None,
+            yul_optimized: None,
+            statistics: None,
+        }
+    }
+
+    ///
+    /// Returns the bytecode sizes.
+    ///
+    pub fn sizes(&self) -> era_solc::StandardJsonOutputContractEraVMSizes {
+        let bytes = self.build.bytecode.len();
+        era_solc::StandardJsonOutputContractEraVMSizes::new(
+            bytes,
+            bytes.div_ceil(era_compiler_common::BYTE_LENGTH_FIELD),
+            self.is_size_fallback,
+        )
+    }
+
+    ///
+    /// Checks whether the linked bytecode can be hashed for EraVM.
+    ///
+    /// The protocol only accepts bytecode of a whole odd number of words.
+    ///
+    pub fn check_size(&self) -> anyhow::Result<()> {
+        let sizes = self.sizes();
+        if sizes.bytes % era_compiler_common::BYTE_LENGTH_FIELD != 0 {
+            anyhow::bail!(
+                "Bytecode size of {} bytes is not a multiple of the {}-byte word size.",
+                sizes.bytes,
+                era_compiler_common::BYTE_LENGTH_FIELD,
+            );
+        }
+        if sizes.words % 2 == 0 {
+            anyhow::bail!(
+                "Bytecode size of {} words must be odd to be hashed for EraVM.",
+                sizes.words,
+            );
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks whether the linked bytecode fits into the EraVM size limit.
+    ///
+    /// The bytecode hash stores the length in words in a 2-byte field.
+    ///
+    pub fn check_size_limit(&self) -> anyhow::Result<()> {
+        let sizes = self.sizes();
+        if sizes.words > crate::r#const::ERAVM_MAX_BYTECODE_WORDS {
+            anyhow::bail!(
+                "Bytecode size of {} words ({} bytes) exceeds the EraVM limit of {} words. Consider using `--size-fallback` or `-Oz`.",
+                sizes.words,
+                sizes.bytes,
+                crate::r#const::ERAVM_MAX_BYTECODE_WORDS,
+            );
         }
+        Ok(())
     }
 
     ///
@@ -183,6 +257,7 @@ impl Contract {
         self,
         standard_json_contract: &mut era_solc::StandardJsonOutputContract,
     ) -> anyhow::Result<()> {
+        let sizes = self.sizes();
         let bytecode = hex::encode(self.build.bytecode.as_slice());
         let assembly = self.build.assembly;
 
@@ -190,6 +265,12 @@ impl Contract {
         standard_json_contract.eravm = Some(era_solc::StandardJsonOutputContractEraVM::new(
             bytecode.clone(),
             assembly.clone(),
+            self.source_map,
+            self.llvm_ir,
+            self.llvm_ir_optimized,
+            self.yul_optimized,
+            sizes,
+            self.statistics,
         ));
         standard_json_contract
             .evm
@@ -218,7 +299,15 @@ impl Contract {
     pub fn write_to_combined_json(
         self,
         combined_json_contract: &mut era_solc::CombinedJsonContract,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        append_cbor: bool,
     ) -> anyhow::Result<()> {
+        // the EraVM metadata is a part of the `metadata` output, so it is only emitted with the latter
+        if combined_json_contract.metadata.is_some() {
+            combined_json_contract.eravm_metadata =
+                Some(self.get_eravm_metadata(metadata_hash_type, append_cbor));
+        }
+
         let hexadecimal_bytecode = hex::encode(self.build.bytecode);
 
         if let Some(metadata) = combined_json_contract.metadata.as_mut() {
@@ -226,10 +315,12 @@ impl Contract {
         }
 
         combined_json_contract.assembly = self.build.assembly;
+        combined_json_contract.srcmap = self.source_map;
         combined_json_contract.bin = Some(hexadecimal_bytecode);
         combined_json_contract
             .bin_runtime
             .clone_from(&combined_json_contract.bin);
+        combined_json_contract.eravm_hash = self.build.bytecode_hash.map(hex::encode);
 
         combined_json_contract
             .missing_libraries
@@ -246,4 +337,60 @@ impl Contract {
 
         Ok(())
     }
+
+    ///
+    /// Extracts the EraVM-specific metadata block from the metadata JSON and the bytecode.
+    ///
+    /// The CBOR entries are decoded from the metadata appended to the bytecode, including
+    /// the metadata hash. Unlinked bytecode is linked with zero placeholders first, as the
+    /// references do not affect the metadata. If the metadata cannot be found, the CBOR
+    /// entries are left empty.
+    ///
+    fn get_eravm_metadata(
+        &self,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        append_cbor: bool,
+    ) -> era_solc::CombinedJsonEraVMMetadata {
+        let cbor = if append_cbor {
+            let bytecode = match self.object_format {
+                era_compiler_common::ObjectFormat::Raw => Some(self.build.bytecode.to_owned()),
+                era_compiler_common::ObjectFormat::ELF => LinkerInspection::try_new(
+                    self.name.full_path.as_str(),
+                    self.build.bytecode.as_slice(),
+                    None,
+                )
+                .ok()
+                .and_then(|inspection| hex::decode(inspection.bytecode).ok()),
+            };
+            Some(
+                bytecode
+                    .and_then(|bytecode| VerifierMetadata::parse(bytecode.as_slice()))
+                    .map(|metadata| metadata.fields)
+                    .unwrap_or_default(),
+            )
+        } else {
+            None
+        };
+        let llvm_options = self
+            .metadata_json
+            .get("llvm_options")
+            .and_then(|value| value.as_array())
+            .map(|options| {
+                options
+                    .iter()
+                    .filter_map(|option| option.as_str().map(str::to_owned))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        era_solc::CombinedJsonEraVMMetadata::new(
+            metadata_hash_type.to_string(),
+            cbor,
+            self.metadata_json
+                .get("optimizer_settings")
+                .cloned()
+                .unwrap_or_default(),
+            llvm_options,
+        )
+    }
 }
@@ -2,6 +2,7 @@
 //! The Solidity project build.
 //!
 
+pub mod assembly_statistics;
 pub mod contract;
 
 use std::collections::BTreeMap;
@@ -13,6 +14,9 @@ use normpath::PathExt;
 
 use era_solc::CollectableError;
 
+use crate::dependency_graph::DependencyGraph;
+use crate::deployment_plan::DeploymentPlan;
+
 use self::contract::Contract;
 
 ///
@@ -43,9 +47,12 @@ impl Build {
     ///
     /// Links the EraVM build.
     ///
+    /// Contracts exceeding the EraVM size limit are reported with warnings, unless `fail_on_size_limit` is set.
+    ///
     pub fn link(
         mut self,
         linker_symbols: BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+        fail_on_size_limit: bool,
     ) -> Self {
         let mut contracts: BTreeMap<String, Contract> = self
             .results
@@ -131,6 +138,43 @@ impl Build {
             }
         }
 
+        let mut dependency_graph = DependencyGraph::default();
+        for (path, contract) in contracts.iter() {
+            dependency_graph.add(
+                path.to_owned(),
+                contract.object_format == era_compiler_common::ObjectFormat::Raw,
+                contract.factory_dependencies.clone(),
+            );
+        }
+        self.messages.extend(dependency_graph.errors());
+
+        for contract in contracts
+            .values()
+            .filter(|contract| contract.object_format == era_compiler_common::ObjectFormat::Raw)
+        {
+            if let Err(error) = contract.check_size() {
+                self.messages
+                    .push(era_solc::StandardJsonOutputError::new_error(
+                        format!("Contract `{}`: {error}", contract.name.full_path),
+                        Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+                            contract.name.path.to_owned(),
+                        )),
+                        None,
+                    ));
+            }
+            if let Err(error) = contract.check_size_limit() {
+                let message = format!("Contract `{}`: {error}", contract.name.full_path);
+                let source_location = Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+                    contract.name.path.to_owned(),
+                ));
+                self.messages.push(if fail_on_size_limit {
+                    era_solc::StandardJsonOutputError::new_error(message, source_location, None)
+                } else {
+                    era_solc::StandardJsonOutputError::new_warning(message, source_location, None)
+                });
+            }
+        }
+
         Self::new(
             contracts
                 .into_iter()
@@ -140,17 +184,127 @@ impl Build {
         )
     }
 
+    ///
+    /// Returns the deployment plan of the linked build.
+    ///
+    pub fn deployment_plan(&self) -> DeploymentPlan {
+        let builds = self
+            .results
+            .iter()
+            .filter_map(|(path, result)| Some((path.to_owned(), result.as_ref().ok()?)))
+            .collect();
+        DeploymentPlan::new(&builds)
+    }
+
+    ///
+    /// Writes the bytecode size report of all contracts to stderr.
+    ///
+    /// Unlinked contracts are marked, as their sizes may change after linking.
+    /// Contracts exceeding the EraVM size limit are marked as well.
+    ///
+    pub fn write_size_report(&self) -> anyhow::Result<()> {
+        let mut stderr = std::io::stderr();
+        writeln!(stderr, "======= Size report =======")?;
+        writeln!(
+            stderr,
+            "{:>10} {:>8}  {:<13}  Contract",
+            "Bytes", "Words", "Size fallback"
+        )?;
+        for contract in self
+            .results
+            .values()
+            .filter_map(|result| result.as_ref().ok())
+        {
+            let sizes = contract.sizes();
+            let unlinked = if contract.object_format == era_compiler_common::ObjectFormat::ELF {
+                " (unlinked)"
+            } else {
+                ""
+            };
+            let exceeding = if contract.check_size_limit().is_err() {
+                format!(
+                    " (exceeds the limit of {} words)",
+                    crate::r#const::ERAVM_MAX_BYTECODE_WORDS
+                )
+            } else {
+                String::new()
+            };
+            writeln!(
+                stderr,
+                "{:>10} {:>8}  {:<13}  {}{unlinked}{exceeding}",
+                sizes.bytes,
+                sizes.words,
+                if sizes.size_fallback { "yes" } else { "no" },
+                contract.name.full_path,
+            )?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Writes the compilation statistics of all contracts to stderr.
+    ///
+    pub fn write_statistics(&self) -> anyhow::Result<()> {
+        let mut stderr = std::io::stderr();
+        writeln!(stderr, "======= Statistics =======")?;
+        for contract in self
+            .results
+            .values()
+            .filter_map(|result| result.as_ref().ok())
+        {
+            let Some(statistics) = contract.statistics.as_ref() else {
+                continue;
+            };
+            writeln!(stderr, "{}:", contract.name.full_path)?;
+            writeln!(
+                stderr,
+                "  Wall time: {} ms (LLVM IR generation: {} ms, LLVM: {} ms){}",
+                statistics.wall_time_ms,
+                statistics.ir_generation_time_ms,
+                statistics.llvm_time_ms,
+                if statistics.cached { ", cached" } else { "" },
+            )?;
+            match statistics.peak_memory_bytes {
+                Some(bytes) => writeln!(stderr, "  Peak memory: {bytes} bytes")?,
+                None => writeln!(stderr, "  Peak memory: unknown")?,
+            }
+            writeln!(stderr, "  Functions: {}", statistics.functions)?;
+            writeln!(
+                stderr,
+                "  Instructions: {}",
+                statistics
+                    .instructions
+                    .iter()
+                    .map(|(category, count)| format!("{category} {count}"))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            )?;
+            writeln!(
+                stderr,
+                "  Size fallback: {}",
+                if statistics.size_fallback {
+                    "yes"
+                } else {
+                    "no"
+                },
+            )?;
+        }
+        Ok(())
+    }
+
     ///
     /// Writes all contracts to the terminal.
     ///
+    /// The warnings and errors are moved to `messages` to be written in the requested diagnostics format.
+    ///
     pub fn write_to_terminal(
         mut self,
+        messages: &mut Vec<era_solc::StandardJsonOutputError>,
         output_metadata: bool,
         output_assembly: bool,
         output_binary: bool,
     ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+        self.collect_messages(messages)?;
 
         if !output_metadata && !output_assembly && !output_binary {
             writeln!(
@@ -175,16 +329,18 @@ impl Build {
     ///
     /// Writes all contracts to the specified directory.
     ///
+    /// The warnings and errors are moved to `messages` to be written in the requested diagnostics format.
+    ///
     pub fn write_to_directory(
         mut self,
+        messages: &mut Vec<era_solc::StandardJsonOutputError>,
         output_directory: &Path,
         output_metadata: bool,
         output_assembly: bool,
         output_binary: bool,
         overwrite: bool,
     ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+        self.collect_messages(messages)?;
 
         std::fs::create_dir_all(output_directory)?;
 
@@ -246,6 +402,7 @@ impl Build {
         }
 
         standard_json.errors.extend(errors);
+        standard_json.errors.extend(self.messages);
         if let Some(solc_version) = solc_version {
             standard_json.version = Some(solc_version.default.to_string());
             standard_json.long_version = Some(solc_version.long.to_owned());
@@ -257,15 +414,19 @@ impl Build {
     ///
     /// Writes all contracts assembly and bytecode to the combined JSON.
     ///
+    /// The warnings and errors are moved to `messages` to be written in the requested diagnostics format.
+    ///
     pub fn write_to_combined_json(
         mut self,
+        messages: &mut Vec<era_solc::StandardJsonOutputError>,
         combined_json: &mut era_solc::CombinedJson,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        append_cbor: bool,
     ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+        self.collect_messages(messages)?;
 
         for result in self.results.into_values() {
-            let build = result.expect("Exits on an error above");
+            let build = result.expect("Bails on an error above");
             let name = build.name.clone();
 
             let combined_json_contract =
@@ -294,7 +455,11 @@ impl Build {
                     }
                 };
 
-            build.write_to_combined_json(combined_json_contract)?;
+            build.write_to_combined_json(
+                combined_json_contract,
+                metadata_hash_type,
+                append_cbor,
+            )?;
         }
 
         Ok(())
@@ -0,0 +1,103 @@
+//!
+//! The persistent on-disk cache of contract builds.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use crate::build_eravm::contract::Contract as EraVMContractBuild;
+use crate::process::input_eravm::Input as EraVMProcessInput;
+
+///
+/// The persistent on-disk cache of contract builds.
+///
+/// Entries are addressed by the hash of the contract IR, the compilation settings, the compiler
+/// version, and the factory dependencies the contract uses, so any change in them results in a miss,
+/// while changes in other contracts of the project do not affect the entry.
+///
+#[derive(Debug, Clone)]
+pub struct Cache {
+    /// The cache directory.
+    pub directory: PathBuf,
+}
+
+impl Cache {
+    /// The cache entry file extension.
+    pub const ENTRY_EXTENSION: &'static str = "json";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Creates the cache directory if it does not exist.
+    ///
+    pub fn try_new(directory: PathBuf) -> anyhow::Result<Self> {
+        std::fs::create_dir_all(directory.as_path())
+            .map_err(|error| anyhow::anyhow!("Cache directory {directory:?} creating: {error}"))?;
+        Ok(Self { directory })
+    }
+
+    ///
+    /// Returns the cache key of the process input.
+    ///
+    /// Only the identifiers of `factory_dependencies` are taken from the project-wide identifier
+    /// mapping. The output flags are part of the key, as they define the artifacts stored in the entry.
+    ///
+    pub fn key(input: &EraVMProcessInput, factory_dependencies: &BTreeSet<String>) -> String {
+        let identifier_paths: BTreeMap<&String, &String> = input
+            .identifier_paths
+            .iter()
+            .filter(|(identifier, _)| factory_dependencies.contains(identifier.as_str()))
+            .collect();
+        let mut input_json = serde_json::to_value(input).expect("Always valid");
+        input_json["identifier_paths"] =
+            serde_json::to_value(identifier_paths).expect("Always valid");
+
+        let mut preimage = format!(
+            "{}:{}:{}:",
+            crate::r#const::DEFAULT_EXECUTABLE_NAME,
+            crate::r#const::version(),
+            inkwell::support::get_commit_id().to_string(),
+        )
+        .into_bytes();
+        preimage.extend(serde_json::to_vec(&input_json).expect("Always valid"));
+        era_compiler_common::Keccak256Hash::from_slice(preimage.as_slice()).to_string()
+    }
+
+    ///
+    /// Returns the cached build, if there is a valid entry for `key`.
+    ///
+    /// Unreadable or corrupted entries are treated as misses.
+    ///
+    pub fn get(&self, key: &str) -> Option<EraVMContractBuild> {
+        let entry = std::fs::read(self.entry_path(key)).ok()?;
+        era_compiler_common::deserialize_from_slice(entry.as_slice()).ok()
+    }
+
+    ///
+    /// Stores the build under `key`.
+    ///
+    /// The entry is written to a temporary file first and then renamed, so concurrent
+    /// compiler runs sharing the directory never observe partially written entries.
+    ///
+    pub fn put(&self, key: &str, build: &EraVMContractBuild) -> anyhow::Result<()> {
+        let entry_path = self.entry_path(key);
+        let temporary_path =
+            entry_path.with_extension(format!("{}.{}", std::process::id(), Self::ENTRY_EXTENSION));
+        let entry = serde_json::to_vec(build).expect("Always valid");
+        std::fs::write(temporary_path.as_path(), entry.as_slice())
+            .map_err(|error| anyhow::anyhow!("Cache entry {temporary_path:?} writing: {error}"))?;
+        std::fs::rename(temporary_path.as_path(), entry_path.as_path())
+            .map_err(|error| anyhow::anyhow!("Cache entry {entry_path:?} writing: {error}"))?;
+        Ok(())
+    }
+
+    ///
+    /// Returns the path to the entry file for `key`.
+    ///
+    fn entry_path(&self, key: &str) -> PathBuf {
+        let key = key.strip_prefix("0x").unwrap_or(key);
+        self.directory
+            .join(format!("{key}.{}", Self::ENTRY_EXTENSION))
+    }
+}
@@ -11,6 +11,9 @@ pub static SOLC_PRODUCTION_NAME: &str = "solc";
 /// The `solc` LLVM revision metadata tag.
 pub static SOLC_LLVM_REVISION_METADATA_TAG: &str = "llvm";
 
+/// The maximum EraVM bytecode size in words, limited by the 2-byte length field of the bytecode hash.
+pub const ERAVM_MAX_BYTECODE_WORDS: usize = (1 << 16) - 1;
+
 /// The worker thread stack size.
 pub const WORKER_THREAD_STACK_SIZE: usize = 64 * 1024 * 1024;
 
@@ -0,0 +1,17 @@
+//!
+//! The factory dependency graph node color.
+//!
+
+///
+/// The factory dependency graph node color.
+///
+/// Nodes without a color are unvisited.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// The node is on the current search path.
+    Gray,
+    /// The node has been searched, and cannot lead back to the start node until
+    /// one of the nodes it depends on is cleared.
+    Black,
+}
@@ -0,0 +1,267 @@
+//!
+//! The factory dependency graph.
+//!
+
+pub mod color;
+pub mod node;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use self::color::Color;
+use self::node::Node;
+
+///
+/// The factory dependency graph.
+///
+/// Used to explain why objects remain unlinked after all linking iterations.
+/// An object cannot be linked until all its factory dependencies are linked, so
+/// dependency cycles and dependencies missing from the input block linking forever.
+///
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct DependencyGraph {
+    /// The objects mapped to their nodes.
+    pub nodes: BTreeMap<String, Node>,
+}
+
+impl DependencyGraph {
+    ///
+    /// Adds an object to the graph.
+    ///
+    pub fn add(&mut self, path: String, linked: bool, factory_dependencies: BTreeSet<String>) {
+        self.nodes
+            .insert(path, Node::new(linked, factory_dependencies));
+    }
+
+    ///
+    /// Returns the factory dependency cycles between unlinked objects.
+    ///
+    /// Each cycle starts and ends with the same object, e.g. `[A, B, A]`.
+    /// All elementary cycles are reported, including the ones sharing objects with others.
+    ///
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let mut cycles = BTreeSet::new();
+        for start in self.unlinked() {
+            let mut stack = Vec::new();
+            let mut colors = BTreeMap::new();
+            let mut blocked_by = BTreeMap::new();
+            self.find_cycles(
+                start,
+                start,
+                &mut stack,
+                &mut colors,
+                &mut blocked_by,
+                &mut cycles,
+            );
+        }
+        cycles.into_iter().collect()
+    }
+
+    ///
+    /// Returns the factory dependencies of unlinked objects that are not found in the graph.
+    ///
+    /// The dependencies are mapped to the set of objects depending on them directly.
+    ///
+    pub fn missing_dependencies(&self) -> BTreeMap<String, BTreeSet<String>> {
+        let mut missing: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for path in self.unlinked() {
+            for dependency in self.nodes[path].factory_dependencies.iter() {
+                if !self.nodes.contains_key(dependency) {
+                    missing
+                        .entry(dependency.to_owned())
+                        .or_default()
+                        .insert(path.to_owned());
+                }
+            }
+        }
+        missing
+    }
+
+    ///
+    /// Returns the unlinked objects that transitively depend on `path`.
+    ///
+    pub fn dependents(&self, path: &str) -> BTreeSet<String> {
+        let mut dependents = BTreeSet::new();
+        let mut queue = vec![path.to_owned()];
+        while let Some(current) = queue.pop() {
+            for dependent in self.unlinked().filter(|dependent| {
+                self.nodes[*dependent]
+                    .factory_dependencies
+                    .contains(current.as_str())
+            }) {
+                if dependent != path && dependents.insert(dependent.to_owned()) {
+                    queue.push(dependent.to_owned());
+                }
+            }
+        }
+        dependents
+    }
+
+    ///
+    /// Returns the errors describing cycles and missing dependencies.
+    ///
+    pub fn errors(&self) -> Vec<era_solc::StandardJsonOutputError> {
+        let mut errors = Vec::new();
+        for cycle in self.cycles() {
+            let path = cycle
+                .iter()
+                .map(|path| format!("`{path}`"))
+                .collect::<Vec<String>>()
+                .join(" → ");
+            errors.push(era_solc::StandardJsonOutputError::new_error(
+                format!("Factory dependency cycle: {path}. Objects in a cycle cannot be linked, as each of them requires the bytecode hash of the other."),
+                None,
+                None,
+            ));
+        }
+        for (dependency, dependents) in self.missing_dependencies() {
+            let mut blocked = BTreeSet::new();
+            for dependent in dependents.iter() {
+                blocked.insert(dependent.to_owned());
+                blocked.extend(self.dependents(dependent.as_str()));
+            }
+            let blocked = blocked
+                .into_iter()
+                .map(|path| format!("`{path}`"))
+                .collect::<Vec<String>>()
+                .join(", ");
+            errors.push(era_solc::StandardJsonOutputError::new_error(
+                format!("Factory dependency `{dependency}` is not found among the input objects. It prevents linking of: {blocked}."),
+                None,
+                None,
+            ));
+        }
+        errors
+    }
+
+    ///
+    /// Returns the graph in the Graphviz DOT format.
+    ///
+    /// Unlinked objects are highlighted, and missing dependencies are drawn dashed.
+    ///
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph factory_dependencies {\n");
+        for (path, node) in self.nodes.iter() {
+            let style = if node.linked { "solid" } else { "bold" };
+            dot.push_str(format!("    \"{path}\" [style={style}];\n").as_str());
+        }
+        for (dependency, _) in self.missing_dependencies() {
+            dot.push_str(format!("    \"{dependency}\" [style=dashed];\n").as_str());
+        }
+        for (path, node) in self.nodes.iter() {
+            for dependency in node.factory_dependencies.iter() {
+                dot.push_str(format!("    \"{path}\" -> \"{dependency}\";\n").as_str());
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    ///
+    /// Writes the graph to the file at `path`.
+    ///
+    /// The DOT format is used for files with the `dot` extension, and JSON otherwise.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let data = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("dot") => self.to_dot().into_bytes(),
+            _ => serde_json::to_vec_pretty(self).expect("Always valid"),
+        };
+        std::fs::write(path, data.as_slice())
+            .map_err(|error| anyhow::anyhow!("Dependency graph file {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Returns the unlinked objects.
+    ///
+    fn unlinked(&self) -> impl Iterator<Item = &String> {
+        self.nodes
+            .iter()
+            .filter(|(_path, node)| !node.linked)
+            .map(|(path, _node)| path)
+    }
+
+    ///
+    /// Returns the unlinked factory dependencies of `path` that are not smaller than `start`.
+    ///
+    fn successors<'a>(&'a self, path: &str, start: &'a str) -> impl Iterator<Item = &'a String> {
+        self.nodes[path]
+            .factory_dependencies
+            .iter()
+            .filter(move |dependency| {
+                dependency.as_str() >= start
+                    && self
+                        .nodes
+                        .get(dependency.as_str())
+                        .is_some_and(|node| !node.linked)
+            })
+    }
+
+    ///
+    /// Collects the cycles through `path` that start with `start` and only contain objects
+    /// not smaller than `start`, so each cycle is reported once.
+    ///
+    /// Uses three-color marking: gray objects are on the current path, and black objects
+    /// have been searched without finding a way back to `start`. Black objects are cleared
+    /// once an object they depend on is found to lead back to `start`, so cycles reached
+    /// through already searched objects are not missed.
+    ///
+    /// Returns whether any cycle has been found.
+    ///
+    fn find_cycles<'a>(
+        &'a self,
+        path: &'a str,
+        start: &'a str,
+        stack: &mut Vec<String>,
+        colors: &mut BTreeMap<&'a str, Color>,
+        blocked_by: &mut BTreeMap<&'a str, BTreeSet<&'a str>>,
+        cycles: &mut BTreeSet<Vec<String>>,
+    ) -> bool {
+        let mut is_found = false;
+        stack.push(path.to_owned());
+        colors.insert(path, Color::Gray);
+        for dependency in self.successors(path, start) {
+            if dependency == start {
+                let mut cycle = stack.clone();
+                cycle.push(start.to_owned());
+                cycles.insert(cycle);
+                is_found = true;
+            } else if !colors.contains_key(dependency.as_str())
+                && self.find_cycles(dependency, start, stack, colors, blocked_by, cycles)
+            {
+                is_found = true;
+            }
+        }
+
+        if is_found {
+            Self::clear(path, colors, blocked_by);
+        } else {
+            colors.insert(path, Color::Black);
+            for dependency in self.successors(path, start) {
+                blocked_by
+                    .entry(dependency.as_str())
+                    .or_default()
+                    .insert(path);
+            }
+        }
+        stack.pop();
+        is_found
+    }
+
+    ///
+    /// Clears the color of `path` and, recursively, of the black objects blocked by it.
+    ///
+    fn clear<'a>(
+        path: &'a str,
+        colors: &mut BTreeMap<&'a str, Color>,
+        blocked_by: &mut BTreeMap<&'a str, BTreeSet<&'a str>>,
+    ) {
+        colors.remove(path);
+        for blocked in blocked_by.remove(path).unwrap_or_default() {
+            if colors.get(blocked) == Some(&Color::Black) {
+                Self::clear(blocked, colors, blocked_by);
+            }
+        }
+    }
+}
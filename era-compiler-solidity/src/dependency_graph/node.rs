@@ -0,0 +1,29 @@
+//!
+//! The factory dependency graph node.
+//!
+
+use std::collections::BTreeSet;
+
+///
+/// The factory dependency graph node.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Node {
+    /// Whether the object has been linked.
+    pub linked: bool,
+    /// The factory dependencies of the object.
+    pub factory_dependencies: BTreeSet<String>,
+}
+
+impl Node {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(linked: bool, factory_dependencies: BTreeSet<String>) -> Self {
+        Self {
+            linked,
+            factory_dependencies,
+        }
+    }
+}
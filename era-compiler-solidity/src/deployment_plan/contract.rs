@@ -0,0 +1,22 @@
+//!
+//! The deployment plan contract entry.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+///
+/// The deployment plan contract entry.
+///
+#[derive(Debug, Default, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    /// The bytecode hashes of factory dependencies that must be published along with the contract,
+    /// including transitive ones, mapped to their contract paths.
+    pub factory_dependencies: BTreeMap<String, String>,
+    /// The factory dependencies whose hashes are unknown, as they are not linked yet.
+    pub factory_dependencies_unlinked: BTreeSet<String>,
+    /// The library symbols that must be linked before the contract can be deployed,
+    /// including the ones of its factory dependencies.
+    pub pending_linker_symbols: BTreeSet<String>,
+}
@@ -0,0 +1,168 @@
+//!
+//! The deployment plan.
+//!
+
+pub mod contract;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::Path;
+
+use crate::build_eravm::contract::Contract as EraVMContractBuild;
+
+use self::contract::Contract;
+
+///
+/// The deployment plan.
+///
+/// Describes the libraries that must be deployed before the project contracts, and what each
+/// contract needs to be deployed.
+///
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct DeploymentPlan {
+    /// The libraries to deploy, in order. Each library is preceded by the libraries it depends on.
+    pub libraries: Vec<String>,
+    /// The contracts mapped to their deployment requirements.
+    pub contracts: BTreeMap<String, Contract>,
+}
+
+impl DeploymentPlan {
+    /// The deployment plan file name in the output directory.
+    pub const FILE_NAME: &'static str = "deployment_plan.json";
+
+    ///
+    /// Creates the deployment plan from linked contract builds.
+    ///
+    pub fn new(builds: &BTreeMap<String, &EraVMContractBuild>) -> Self {
+        let mut contracts = BTreeMap::new();
+        for path in builds.keys() {
+            let mut contract = Contract::default();
+
+            let mut visited = BTreeSet::new();
+            let mut queue = vec![path.to_owned()];
+            while let Some(current) = queue.pop() {
+                if !visited.insert(current.clone()) {
+                    continue;
+                }
+                let Some(build) = builds.get(current.as_str()) else {
+                    contract.factory_dependencies_unlinked.insert(current);
+                    continue;
+                };
+                if build.object_format == era_compiler_common::ObjectFormat::ELF {
+                    contract
+                        .pending_linker_symbols
+                        .extend(build.missing_libraries.iter().cloned());
+                }
+                if current != *path {
+                    match build.build.bytecode_hash {
+                        Some(hash) => {
+                            contract
+                                .factory_dependencies
+                                .insert(hex::encode(hash), current.clone());
+                        }
+                        None => {
+                            contract
+                                .factory_dependencies_unlinked
+                                .insert(current.clone());
+                        }
+                    }
+                }
+                queue.extend(build.factory_dependencies.iter().cloned());
+            }
+
+            contracts.insert(path.to_owned(), contract);
+        }
+
+        let libraries = Self::sort_libraries(&contracts);
+        Self {
+            libraries,
+            contracts,
+        }
+    }
+
+    ///
+    /// Writes the deployment plan to the terminal.
+    ///
+    pub fn write_to_terminal(&self) -> anyhow::Result<()> {
+        writeln!(std::io::stdout(), "\n======= Deployment plan =======")?;
+        writeln!(
+            std::io::stdout(),
+            "{}",
+            serde_json::to_string_pretty(self).expect("Always valid")
+        )?;
+        Ok(())
+    }
+
+    ///
+    /// Writes the deployment plan to the output directory.
+    ///
+    pub fn write_to_directory(
+        &self,
+        output_directory: &Path,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        let output_path = output_directory.join(Self::FILE_NAME);
+        if output_path.exists() && !overwrite {
+            anyhow::bail!(
+                "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+            );
+        }
+        std::fs::write(
+            output_path.as_path(),
+            serde_json::to_vec_pretty(self).expect("Always valid"),
+        )
+        .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))
+    }
+
+    ///
+    /// Sorts the pending libraries so that each one follows the libraries it depends on.
+    ///
+    /// Libraries in dependency cycles cannot be ordered, so they are appended at the end.
+    ///
+    fn sort_libraries(contracts: &BTreeMap<String, Contract>) -> Vec<String> {
+        let mut dependencies: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        let mut queue: Vec<String> = contracts
+            .values()
+            .flat_map(|contract| contract.pending_linker_symbols.iter().cloned())
+            .collect();
+        while let Some(library) = queue.pop() {
+            if dependencies.contains_key(library.as_str()) {
+                continue;
+            }
+            let library_dependencies = match contracts.get(library.as_str()) {
+                Some(contract) => contract
+                    .pending_linker_symbols
+                    .iter()
+                    .filter(|dependency| *dependency != &library)
+                    .cloned()
+                    .collect(),
+                None => BTreeSet::new(),
+            };
+            queue.extend(library_dependencies.iter().cloned());
+            dependencies.insert(library, library_dependencies);
+        }
+
+        let mut libraries = Vec::with_capacity(dependencies.len());
+        while !dependencies.is_empty() {
+            let ready: Vec<String> = dependencies
+                .iter()
+                .filter(|(_library, library_dependencies)| {
+                    library_dependencies
+                        .iter()
+                        .all(|dependency| !dependencies.contains_key(dependency.as_str()))
+                })
+                .map(|(library, _library_dependencies)| library.to_owned())
+                .collect();
+            if ready.is_empty() {
+                libraries.extend(std::mem::take(&mut dependencies).into_keys());
+                break;
+            }
+            for library in ready.into_iter() {
+                dependencies.remove(library.as_str());
+                libraries.push(library);
+            }
+        }
+        libraries
+    }
+}
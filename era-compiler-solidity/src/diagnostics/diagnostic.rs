@@ -0,0 +1,123 @@
+//!
+//! The CLI diagnostic.
+//!
+
+///
+/// The CLI diagnostic.
+///
+/// A flattened representation of the `solc` standard JSON error, with the source location
+/// mapped to line and column.
+///
+/// The error locations are 0-based byte offsets, both in the `solc` errors and in the ones
+/// created from the `solc` AST, so the line and column are resolved from the offset here.
+/// The column printed in the human-readable format is relative to the offset base and
+/// cannot be reused as is.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+    /// The severity: `error`, `warning`, or `info`.
+    pub severity: String,
+    /// The message type, e.g. `TypeError` or `Warning`.
+    pub r#type: String,
+    /// The `solc` error code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    /// The non-formatted message.
+    pub message: String,
+    /// The source file path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// The line number, starting from 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// The column number, starting from 1, in UTF-16 code units as expected by SARIF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    /// The error area length.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<usize>,
+}
+
+impl Diagnostic {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The `source_code` of the error file is required to resolve the line and column.
+    ///
+    pub fn new(error: &era_solc::StandardJsonOutputError, source_code: Option<&str>) -> Self {
+        let mapped_location = error.source_location.as_ref().map(|source_location| {
+            era_solc::StandardJsonOutputErrorMappedLocation::try_from_source_location(
+                source_location,
+                source_code,
+            )
+        });
+        let position = error.source_location.as_ref().zip(source_code).and_then(
+            |(source_location, source_code)| Self::position(source_code, source_location.start),
+        );
+
+        Self {
+            severity: error.severity.to_owned(),
+            r#type: error.r#type.to_owned(),
+            error_code: error.error_code.to_owned(),
+            message: error.message.trim().to_owned(),
+            file: mapped_location
+                .as_ref()
+                .map(|location| location.path.to_owned()),
+            line: position.map(|(line, _column)| line),
+            column: position.map(|(_line, column)| column),
+            length: mapped_location.and_then(|location| location.length),
+        }
+    }
+
+    ///
+    /// Converts the diagnostic into a SARIF result object.
+    ///
+    pub fn to_sarif_result(&self) -> serde_json::Value {
+        let level = match self.severity.as_str() {
+            "error" => "error",
+            "warning" => "warning",
+            _ => "note",
+        };
+
+        let mut result = serde_json::json!({
+            "ruleId": self.error_code.as_deref().unwrap_or(self.r#type.as_str()),
+            "level": level,
+            "message": {
+                "text": self.message,
+            },
+        });
+        if let Some(ref file) = self.file {
+            let mut physical_location = serde_json::json!({
+                "artifactLocation": {
+                    "uri": file,
+                },
+            });
+            if let Some(line) = self.line {
+                let mut region = serde_json::json!({
+                    "startLine": line,
+                });
+                if let Some(column) = self.column {
+                    region["startColumn"] = serde_json::json!(column);
+                }
+                physical_location["region"] = region;
+            }
+            result["locations"] = serde_json::json!([{
+                "physicalLocation": physical_location,
+            }]);
+        }
+        result
+    }
+
+    ///
+    /// Resolves the 1-based line and column of the 0-based byte `offset` in `source_code`.
+    ///
+    fn position(source_code: &str, offset: isize) -> Option<(usize, usize)> {
+        let offset = usize::try_from(offset).ok()?;
+        let preceding = source_code.get(..offset)?;
+        let line_start = preceding.rfind('\n').map_or(0, |index| index + 1);
+        let line = preceding.matches('\n').count() + 1;
+        let column = preceding[line_start..].encode_utf16().count() + 1;
+        Some((line, column))
+    }
+}
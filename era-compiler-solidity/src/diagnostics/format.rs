@@ -0,0 +1,52 @@
+//!
+//! The CLI diagnostics format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The CLI diagnostics format.
+///
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// The formatted messages, as printed by `solc`.
+    #[default]
+    Human,
+    /// One JSON object per line.
+    JSON,
+    /// The SARIF v2.1.0 log.
+    SARIF,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::JSON),
+            "sarif" => Ok(Self::SARIF),
+            string => anyhow::bail!(
+                "Invalid diagnostics format: `{string}`. Available options: {}.",
+                [Self::Human, Self::JSON, Self::SARIF]
+                    .into_iter()
+                    .map(|format| format.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Human => write!(f, "human"),
+            Self::JSON => write!(f, "json"),
+            Self::SARIF => write!(f, "sarif"),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//!
+//! The CLI diagnostics output.
+//!
+
+pub mod diagnostic;
+pub mod format;
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+
+use self::diagnostic::Diagnostic;
+use self::format::Format;
+
+/// The SARIF specification version.
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// The SARIF JSON schema.
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+///
+/// Writes the collected `messages` in the specified `format`.
+///
+/// To map the error offsets to lines and columns, the source files are read relative to
+/// the current directory, `base_path`, and `include_paths`, in that order.
+///
+pub fn write<W>(
+    writer: &mut W,
+    messages: &[era_solc::StandardJsonOutputError],
+    format: Format,
+    base_path: Option<&str>,
+    include_paths: &[String],
+) -> anyhow::Result<()>
+where
+    W: Write,
+{
+    match format {
+        Format::Human => {
+            if !messages.is_empty() {
+                writeln!(
+                    writer,
+                    "{}",
+                    messages
+                        .iter()
+                        .map(|message| message.to_string())
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                )?;
+            }
+        }
+        Format::JSON => {
+            for diagnostic in diagnostics(messages, base_path, include_paths).into_iter() {
+                serde_json::to_writer(&mut *writer, &diagnostic)?;
+                writeln!(writer)?;
+            }
+        }
+        Format::SARIF => {
+            let log = serde_json::json!({
+                "$schema": SARIF_SCHEMA,
+                "version": SARIF_VERSION,
+                "runs": [{
+                    "tool": {
+                        "driver": {
+                            "name": crate::r#const::DEFAULT_EXECUTABLE_NAME,
+                            "version": crate::r#const::version(),
+                        },
+                    },
+                    "results": diagnostics(messages, base_path, include_paths)
+                        .iter()
+                        .map(Diagnostic::to_sarif_result)
+                        .collect::<Vec<serde_json::Value>>(),
+                }],
+            });
+            serde_json::to_writer(&mut *writer, &log)?;
+            writeln!(writer)?;
+        }
+    }
+    Ok(())
+}
+
+///
+/// Converts the `messages` into diagnostics, reading the source files they refer to.
+///
+fn diagnostics(
+    messages: &[era_solc::StandardJsonOutputError],
+    base_path: Option<&str>,
+    include_paths: &[String],
+) -> Vec<Diagnostic> {
+    let mut sources: BTreeMap<&str, Option<String>> = BTreeMap::new();
+    for source_location in messages
+        .iter()
+        .filter_map(|message| message.source_location.as_ref())
+    {
+        sources
+            .entry(source_location.file.as_str())
+            .or_insert_with(|| {
+                std::iter::once("")
+                    .chain(base_path)
+                    .chain(include_paths.iter().map(String::as_str))
+                    .find_map(|directory| {
+                        std::fs::read_to_string(
+                            Path::new(directory).join(source_location.file.as_str()),
+                        )
+                        .ok()
+                    })
+            });
+    }
+
+    messages
+        .iter()
+        .map(|message| {
+            let source_code = message
+                .source_location
+                .as_ref()
+                .and_then(|source_location| sources.get(source_location.file.as_str()))
+                .and_then(|source_code| source_code.as_deref());
+            Diagnostic::new(message, source_code)
+        })
+        .collect()
+}
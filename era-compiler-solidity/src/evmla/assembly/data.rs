@@ -2,7 +2,7 @@
 //! The inner JSON legacy assembly code element.
 //!
 
-use std::collections::BTreeSet;
+use std::collections::BTreeMap;
 
 use crate::evmla::assembly::Assembly;
 
@@ -43,13 +43,15 @@ impl Data {
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their references.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(
+        &self,
+    ) -> BTreeMap<String, Vec<era_solc::StandardJsonOutputContractMissingLibraryLocation>> {
         match self {
             Self::Assembly(assembly) => assembly.get_missing_libraries(),
-            Self::Hash(_) => BTreeSet::new(),
-            Self::Path(_) => BTreeSet::new(),
+            Self::Hash(_) => BTreeMap::new(),
+            Self::Path(_) => BTreeMap::new(),
         }
     }
 }
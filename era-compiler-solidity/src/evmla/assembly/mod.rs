@@ -6,7 +6,6 @@ pub mod data;
 pub mod instruction;
 
 use std::collections::BTreeMap;
-use std::collections::BTreeSet;
 use std::collections::HashSet;
 
 use rayon::iter::IntoParallelIterator;
@@ -108,21 +107,37 @@ impl Assembly {
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their references.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
-        let mut missing_libraries = BTreeSet::new();
+    pub fn get_missing_libraries(
+        &self,
+    ) -> BTreeMap<String, Vec<era_solc::StandardJsonOutputContractMissingLibraryLocation>> {
+        let mut missing_libraries = BTreeMap::new();
         if let Some(code) = self.code.as_ref() {
             for instruction in code.iter() {
                 if let InstructionName::PUSHLIB = instruction.name {
                     let library_path = instruction.value.to_owned().expect("Always exists");
-                    missing_libraries.insert(library_path);
+                    missing_libraries
+                        .entry(library_path)
+                        .or_insert_with(Vec::new)
+                        .push(
+                            era_solc::StandardJsonOutputContractMissingLibraryLocation::EVMLA {
+                                source: instruction.source,
+                                begin: instruction.begin,
+                                end: instruction.end,
+                            },
+                        );
                 }
             }
         }
         if let Some(data) = self.data.as_ref() {
             for (_, data) in data.iter() {
-                missing_libraries.extend(data.get_missing_libraries());
+                for (library, locations) in data.get_missing_libraries().into_iter() {
+                    missing_libraries
+                        .entry(library)
+                        .or_insert_with(Vec::new)
+                        .extend(locations);
+                }
             }
         }
         missing_libraries
@@ -23,6 +23,8 @@ use self::stack::Stack;
 pub struct Element {
     /// The instruction.
     pub instruction: Instruction,
+    /// The instruction offset in its code segment.
+    pub offset: usize,
     /// The stack data.
     pub stack: Stack,
     /// The stack input.
@@ -35,12 +37,13 @@ impl Element {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(solc_version: semver::Version, instruction: Instruction) -> Self {
+    pub fn new(solc_version: semver::Version, instruction: Instruction, offset: usize) -> Self {
         let input_size = instruction.input_size(&solc_version);
         let output_size = instruction.output_size();
 
         Self {
             instruction,
+            offset,
             stack: Stack::new(),
             stack_input: Stack::with_capacity(input_size),
             stack_output: Stack::with_capacity(output_size),
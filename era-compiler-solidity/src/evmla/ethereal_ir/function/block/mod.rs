@@ -12,6 +12,7 @@ use era_compiler_llvm_context::IContext;
 
 use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::instruction::Instruction;
+use crate::source_map::SourceMap;
 
 use self::element::stack::Stack as ElementStack;
 use self::element::Element;
@@ -44,12 +45,13 @@ impl Block {
     pub const PREDECESSORS_HASHSET_DEFAULT_CAPACITY: usize = 4;
 
     ///
-    /// Assembles a block from the sequence of instructions.
+    /// Assembles a block from the sequence of instructions starting at `offset` in the code segment.
     ///
     pub fn try_from_instructions(
         solc_version: semver::Version,
         code_segment: era_compiler_common::CodeSegment,
         slice: &[Instruction],
+        offset: usize,
     ) -> anyhow::Result<(Self, usize)> {
         let mut cursor = 0;
 
@@ -80,7 +82,11 @@ impl Block {
         let mut dead_code = false;
         while cursor < slice.len() {
             if !dead_code {
-                let element: Element = Element::new(solc_version.clone(), slice[cursor].to_owned());
+                let element: Element = Element::new(
+                    solc_version.clone(),
+                    slice[cursor].to_owned(),
+                    offset + cursor,
+                );
                 block.elements.push(element);
             }
 
@@ -128,6 +134,11 @@ impl era_compiler_llvm_context::EraVMWriteLLVM for Block {
         context.set_code_segment(self.key.code_segment);
 
         for element in self.elements.into_iter() {
+            if SourceMap::is_debug_location_required() {
+                let (line, column) =
+                    SourceMap::evmla_debug_location(self.key.code_segment, element.offset);
+                context.set_debug_location(line as u32, column as u32, None)?;
+            }
             element.into_llvm(context)?;
         }
 
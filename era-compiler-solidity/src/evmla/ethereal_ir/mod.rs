@@ -86,6 +86,7 @@ impl EtherealIR {
                 solc_version.clone(),
                 code_segment,
                 &instructions[offset..],
+                offset,
             )?;
             blocks.insert(
                 era_compiler_llvm_context::BlockKey::new(code_segment, block.key.tag.clone()),
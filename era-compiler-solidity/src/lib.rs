@@ -10,19 +10,41 @@
 #![allow(clippy::result_large_err)]
 
 pub mod build_eravm;
+pub mod cache;
 pub mod r#const;
+pub mod dependency_graph;
+pub mod deployment_plan;
+pub mod diagnostics;
 pub mod evmla;
+pub mod libraries_file;
 pub mod linker;
 pub mod missing_libraries;
 pub mod process;
 pub mod project;
+pub mod session;
+pub mod source_map;
+pub mod verifier;
+pub mod watch;
 pub mod yul;
 
+pub use self::build_eravm::assembly_statistics::AssemblyStatistics as EraVMAssemblyStatistics;
 pub use self::build_eravm::contract::Contract as EraVMContractBuild;
 pub use self::build_eravm::Build as EraVMBuild;
+pub use self::cache::Cache;
+pub use self::dependency_graph::DependencyGraph;
+pub use self::deployment_plan::DeploymentPlan;
+pub use self::diagnostics::diagnostic::Diagnostic;
+pub use self::diagnostics::format::Format as DiagnosticsFormat;
+pub use self::diagnostics::write as write_diagnostics;
+pub use self::libraries_file::LibrariesFile;
+pub use self::linker::create2::Create2 as LinkerCreate2;
+pub use self::linker::file_format::FileFormat as LinkerFileFormat;
 pub use self::linker::input::Input as LinkerInput;
+pub use self::linker::inspection::Inspection as LinkerInspection;
 pub use self::linker::output::Output as LinkerOutput;
 pub use self::linker::Linker;
+pub use self::missing_libraries::MissingLibraries;
+pub use self::process::backend::Backend as ProcessBackend;
 pub use self::process::input_eravm::Input as EraVMProcessInput;
 pub use self::process::output_eravm::Output as EraVMProcessOutput;
 pub use self::process::run as run_recursive;
@@ -30,6 +52,12 @@ pub use self::process::EXECUTABLE;
 pub use self::project::contract::Contract as ProjectContract;
 pub use self::project::Project;
 pub use self::r#const::*;
+pub use self::session::builder::Builder as CompilerBuilder;
+pub use self::session::Session as CompilationSession;
+pub use self::source_map::SourceMap;
+pub use self::verifier::report::Report as VerifierReport;
+pub use self::verifier::Verifier;
+pub use self::watch::Watch;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
@@ -61,7 +89,11 @@ pub fn yul_to_eravm(
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     output_assembly: bool,
+    output_statistics: bool,
+    fail_on_size_limit: bool,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    backend: ProcessBackend,
+    cache: Option<Cache>,
 ) -> anyhow::Result<EraVMBuild> {
     let libraries = era_compiler_common::Libraries::try_from(libraries)?;
     let linker_symbols = libraries.as_linker_symbols()?;
@@ -94,14 +126,19 @@ pub fn yul_to_eravm(
         optimizer_settings,
         llvm_options,
         output_assembly,
+        false,
+        false,
+        false,
+        false,
+        output_statistics,
         debug_config,
+        backend,
+        cache.as_ref(),
     )?;
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.collect_messages(messages)?;
 
-    let mut build = build.link(linker_symbols);
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    let mut build = build.link(linker_symbols, fail_on_size_limit);
+    build.collect_messages(messages)?;
     Ok(build)
 }
 
@@ -117,7 +154,11 @@ pub fn llvm_ir_to_eravm(
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     output_assembly: bool,
+    output_statistics: bool,
+    fail_on_size_limit: bool,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    backend: ProcessBackend,
+    cache: Option<Cache>,
 ) -> anyhow::Result<EraVMBuild> {
     let libraries = era_compiler_common::Libraries::try_from(libraries)?;
     let linker_symbols = libraries.as_linker_symbols()?;
@@ -132,14 +173,19 @@ pub fn llvm_ir_to_eravm(
         optimizer_settings,
         llvm_options,
         output_assembly,
+        false,
+        false,
+        false,
+        false,
+        output_statistics,
         debug_config,
+        backend,
+        cache.as_ref(),
     )?;
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.collect_messages(messages)?;
 
-    let mut build = build.link(linker_symbols);
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    let mut build = build.link(linker_symbols, fail_on_size_limit);
+    build.collect_messages(messages)?;
     Ok(build)
 }
 
@@ -153,7 +199,11 @@ pub fn eravm_assembly_to_eravm(
     append_cbor: bool,
     llvm_options: Vec<String>,
     output_assembly: bool,
+    output_statistics: bool,
+    fail_on_size_limit: bool,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    backend: ProcessBackend,
+    cache: Option<Cache>,
 ) -> anyhow::Result<EraVMBuild> {
     let project = Project::try_from_eravm_assembly_paths(paths, None)?;
 
@@ -166,22 +216,31 @@ pub fn eravm_assembly_to_eravm(
         optimizer_settings,
         llvm_options,
         output_assembly,
+        false,
+        false,
+        false,
+        false,
+        output_statistics,
         debug_config,
+        backend,
+        cache.as_ref(),
     )?;
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    build.collect_messages(messages)?;
 
-    let mut build = build.link(BTreeMap::new());
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    let mut build = build.link(BTreeMap::new(), fail_on_size_limit);
+    build.collect_messages(messages)?;
     Ok(build)
 }
 
 ///
 /// Runs the standard output mode for the EraVM target.
 ///
+/// If `selected_contracts` is not empty, only the selected contracts and their factory
+/// dependencies are compiled.
+///
 pub fn standard_output_eravm(
     paths: &[PathBuf],
+    selected_contracts: &BTreeSet<String>,
     libraries: &[String],
     solc_compiler: &era_solc::Compiler,
     messages: &mut Vec<era_solc::StandardJsonOutputError>,
@@ -198,9 +257,15 @@ pub fn standard_output_eravm(
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     output_assembly: bool,
+    output_source_map: bool,
+    output_statistics: bool,
+    fail_on_size_limit: bool,
     suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
     suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    backend: ProcessBackend,
+    cache: Option<Cache>,
+    mut watch: Option<&mut Watch>,
 ) -> anyhow::Result<EraVMBuild> {
     let solc_version = solc_compiler.version.to_owned();
     let solc_codegen = era_solc::StandardJsonInputCodegen::new(&solc_version, codegen);
@@ -229,41 +294,72 @@ pub fn standard_output_eravm(
     let mut solc_output = solc_compiler.standard_json(
         &mut solc_input,
         messages,
-        base_path,
-        include_paths,
+        base_path.clone(),
+        include_paths.clone(),
         allow_paths,
     )?;
-    solc_output.take_and_write_warnings();
-    solc_output.check_errors()?;
+    if let Some(watch) = watch.as_deref_mut() {
+        watch.watch_files(
+            paths,
+            &solc_output,
+            base_path.as_deref(),
+            include_paths.as_slice(),
+        );
+    }
+    solc_output.collect_messages(messages)?;
 
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
 
-    let project = Project::try_from_solc_output(
+    let mut project = Project::try_from_solc_output(
         solc_input.settings.libraries,
         solc_codegen,
         &mut solc_output,
         solc_compiler,
         debug_config.as_ref(),
     )?;
-    solc_output.take_and_write_warnings();
-    solc_output.check_errors()?;
+    solc_output.collect_messages(messages)?;
+    if !selected_contracts.is_empty() {
+        project.retain_selected_contracts(selected_contracts)?;
+    }
 
-    let mut build = project.compile_to_eravm(
-        messages,
-        enable_eravm_extensions,
-        metadata_hash_type,
-        append_cbor,
-        optimizer_settings,
-        llvm_options,
-        output_assembly,
-        debug_config,
-    )?;
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    let mut build = match watch {
+        Some(watch) => watch.compile_to_eravm(
+            project,
+            messages,
+            enable_eravm_extensions,
+            metadata_hash_type,
+            append_cbor,
+            optimizer_settings,
+            llvm_options,
+            output_assembly,
+            output_source_map,
+            output_statistics,
+            debug_config,
+            backend,
+            cache.as_ref(),
+        )?,
+        None => project.compile_to_eravm(
+            messages,
+            enable_eravm_extensions,
+            metadata_hash_type,
+            append_cbor,
+            optimizer_settings,
+            llvm_options,
+            output_assembly,
+            output_source_map,
+            false,
+            false,
+            false,
+            output_statistics,
+            debug_config,
+            backend,
+            cache.as_ref(),
+        )?,
+    };
+    build.collect_messages(messages)?;
 
-    let mut build = build.link(linker_symbols);
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    let mut build = build.link(linker_symbols, fail_on_size_limit);
+    build.collect_messages(messages)?;
     Ok(build)
 }
 
@@ -281,10 +377,50 @@ pub fn standard_json_eravm(
     include_paths: Vec<String>,
     allow_paths: Option<String>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    backend: ProcessBackend,
+    cache: Option<Cache>,
 ) -> anyhow::Result<()> {
-    let mut solc_input = era_solc::StandardJsonInput::try_from(json_path.as_deref())?;
-    let language = solc_input.language;
+    let solc_input = era_solc::StandardJsonInput::try_from(json_path.as_deref())?;
     let prune_output = solc_input.settings.selection_to_prune();
+
+    let solc_output = standard_json_eravm_output(
+        solc_compiler,
+        codegen,
+        enable_eravm_extensions,
+        detect_missing_libraries,
+        solc_input,
+        messages,
+        base_path,
+        include_paths,
+        allow_paths,
+        debug_config,
+        backend,
+        cache,
+    )?;
+    solc_output.write_and_exit(prune_output);
+}
+
+///
+/// Runs the standard JSON mode for the EraVM target, and returns the output instead of printing it.
+///
+/// The output is not pruned, so the caller is expected to call `StandardJsonOutput::prune`
+/// with the input selection.
+///
+pub fn standard_json_eravm_output(
+    solc_compiler: Option<era_solc::Compiler>,
+    codegen: Option<era_solc::StandardJsonInputCodegen>,
+    enable_eravm_extensions: bool,
+    detect_missing_libraries: bool,
+    mut solc_input: era_solc::StandardJsonInput,
+    messages: &mut Vec<era_solc::StandardJsonOutputError>,
+    base_path: Option<String>,
+    include_paths: Vec<String>,
+    allow_paths: Option<String>,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    backend: ProcessBackend,
+    cache: Option<Cache>,
+) -> anyhow::Result<era_solc::StandardJsonOutput> {
+    let language = solc_input.language;
     let deployed_libraries = solc_input.settings.libraries.as_paths();
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
 
@@ -295,6 +431,10 @@ pub fn standard_json_eravm(
         optimizer_settings.enable_fallback_to_size();
     }
     let llvm_options = solc_input.settings.llvm_options.clone();
+    let cache = match solc_input.settings.cache_dir.take() {
+        Some(cache_dir) => Some(Cache::try_new(cache_dir)?),
+        None => cache,
+    };
 
     let codegen = if solc_input.settings.force_evmla {
         Some(era_solc::StandardJsonInputCodegen::EVMLA)
@@ -314,8 +454,36 @@ pub fn standard_json_eravm(
         .settings
         .output_selection
         .contains(&era_solc::StandardJsonInputSelector::EraVMAssembly);
+    let output_source_map = solc_input
+        .settings
+        .output_selection
+        .contains(&era_solc::StandardJsonInputSelector::EraVMSourceMap);
+    let output_llvm_ir = solc_input
+        .settings
+        .output_selection
+        .contains(&era_solc::StandardJsonInputSelector::EraVMLLVMIR);
+    let output_llvm_ir_optimized = solc_input
+        .settings
+        .output_selection
+        .contains(&era_solc::StandardJsonInputSelector::EraVMLLVMIROptimized);
+    let output_yul_optimized = solc_input
+        .settings
+        .output_selection
+        .contains(&era_solc::StandardJsonInputSelector::EraVMYulOptimized);
+    let output_statistics = solc_input
+        .settings
+        .output_selection
+        .contains(&era_solc::StandardJsonInputSelector::EraVMStatistics);
+    let output_link_references = solc_input
+        .settings
+        .output_selection
+        .contains(&era_solc::StandardJsonInputSelector::EraVMLinkReferences);
+    let output_factory_dependency_references = solc_input
+        .settings
+        .output_selection
+        .contains(&era_solc::StandardJsonInputSelector::EraVMFactoryDependencyReferences);
 
-    let (mut solc_output, solc_version, project) = match (language, solc_compiler) {
+    let (mut solc_output, solc_version, mut project) = match (language, solc_compiler) {
         (era_solc::StandardJsonInputLanguage::Solidity, solc_compiler) => {
             let solc_compiler = match solc_compiler {
                 Some(solc_compiler) => solc_compiler,
@@ -336,7 +504,7 @@ pub fn standard_json_eravm(
                 allow_paths,
             )?;
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
             let project = Project::try_from_solc_output(
@@ -347,7 +515,7 @@ pub fn standard_json_eravm(
                 debug_config.as_ref(),
             )?;
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
             (solc_output, Some(solc_compiler.version), project)
@@ -356,7 +524,7 @@ pub fn standard_json_eravm(
             let mut solc_output =
                 solc_compiler.validate_yul_standard_json(&mut solc_input, messages)?;
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
             let project = Project::try_from_yul_sources(
@@ -367,7 +535,7 @@ pub fn standard_json_eravm(
                 debug_config.as_ref(),
             )?;
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
             (solc_output, Some(solc_compiler.version), project)
@@ -383,7 +551,7 @@ pub fn standard_json_eravm(
                 debug_config.as_ref(),
             )?;
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
             (solc_output, None, project)
@@ -400,7 +568,7 @@ pub fn standard_json_eravm(
                 Some(&mut solc_output),
             )?;
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
             (solc_output, None, project)
@@ -416,17 +584,37 @@ pub fn standard_json_eravm(
                 Some(&mut solc_output),
             )?;
             if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
+                return Ok(solc_output);
             }
 
             (solc_output, None, project)
         }
     };
 
+    let missing_libraries = project.get_missing_libraries(&deployed_libraries);
     if detect_missing_libraries {
-        let missing_libraries = project.get_missing_libraries(&deployed_libraries);
         missing_libraries.write_to_standard_json(&mut solc_output, solc_version.as_ref());
-        solc_output.write_and_exit(prune_output);
+        return Ok(solc_output);
+    }
+
+    let output_selection = &solc_input.settings.output_selection;
+    if output_selection.is_codegen_selective() {
+        let selected_contracts = project
+            .contracts
+            .iter()
+            .filter(|(_path, contract)| {
+                output_selection.requires_codegen(
+                    contract.name.path.as_str(),
+                    contract
+                        .name
+                        .name
+                        .as_deref()
+                        .unwrap_or(contract.name.path.as_str()),
+                )
+            })
+            .map(|(path, _contract)| path.to_owned())
+            .collect();
+        project.retain_selected_contracts(&selected_contracts)?;
     }
 
     let build = project.compile_to_eravm(
@@ -437,16 +625,33 @@ pub fn standard_json_eravm(
         optimizer_settings,
         llvm_options,
         output_assembly,
+        output_source_map,
+        output_llvm_ir,
+        output_llvm_ir_optimized,
+        output_yul_optimized,
+        output_statistics,
         debug_config,
+        backend,
+        cache.as_ref(),
     )?;
     if build.has_errors() {
         build.write_to_standard_json(&mut solc_output, solc_version.as_ref())?;
-        solc_output.write_and_exit(prune_output);
+        return Ok(solc_output);
     }
 
-    let build = build.link(linker_symbols);
+    let build = build.link(linker_symbols, false);
+    if output_selection.contains(&era_solc::StandardJsonInputSelector::EraVMDeploymentPlan) {
+        solc_output.deployment_plan =
+            Some(serde_json::to_value(build.deployment_plan()).expect("Always valid"));
+    }
     build.write_to_standard_json(&mut solc_output, solc_version.as_ref())?;
-    solc_output.write_and_exit(prune_output);
+    LinkerInspection::write_to_standard_json(
+        &mut solc_output,
+        output_link_references,
+        output_factory_dependency_references,
+    );
+    missing_libraries.write_details_to_standard_json(&mut solc_output);
+    Ok(solc_output)
 }
 
 ///
@@ -470,11 +675,16 @@ pub fn combined_json_eravm(
     remappings: BTreeSet<String>,
     output_directory: Option<PathBuf>,
     overwrite: bool,
+    profile: era_solc::CombinedJsonProfile,
+    per_contract: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
     suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    backend: ProcessBackend,
+    cache: Option<Cache>,
+    watch: Option<&mut Watch>,
 ) -> anyhow::Result<()> {
     let selector_results = era_solc::CombinedJsonSelector::from_cli(format.as_str());
     let mut selectors = HashSet::with_capacity(selector_results.len());
@@ -500,18 +710,22 @@ pub fn combined_json_eravm(
         ));
     }
     if selectors.contains(&era_solc::CombinedJsonSelector::BytecodeRuntime) {
+        let message = match profile {
+            era_solc::CombinedJsonProfile::Compatible => format!("The `{}` selector does not make sense for the {} target, since there is only one bytecode segment. The eponymous output field will be removed in future releases of `zksolc`.", era_solc::CombinedJsonSelector::BytecodeRuntime, era_compiler_common::Target::EraVM),
+            era_solc::CombinedJsonProfile::Strict => format!("The `{}` selector is ignored in the `{}` combined JSON profile.", era_solc::CombinedJsonSelector::BytecodeRuntime, profile),
+        };
         messages.push(era_solc::StandardJsonOutputError::new_warning(
-            format!("The `{}` selector does not make sense for the {} target, since there is only one bytecode segment. The eponymous output field will be removed in future releases of `zksolc`.", era_solc::CombinedJsonSelector::BytecodeRuntime, era_compiler_common::Target::EraVM),
-            None,
-            None,
+            message, None, None,
         ));
     }
     let output_assembly = selectors.contains(&era_solc::CombinedJsonSelector::Assembly);
+    let output_source_map = selectors.contains(&era_solc::CombinedJsonSelector::SourceMap);
 
     let mut combined_json = solc_compiler.combined_json(paths, selectors, codegen)?;
 
     let build = standard_output_eravm(
         paths,
+        &BTreeSet::new(),
         libraries,
         solc_compiler,
         messages,
@@ -528,16 +742,35 @@ pub fn combined_json_eravm(
         optimizer_settings,
         llvm_options,
         output_assembly,
+        output_source_map,
+        false,
+        false,
         suppressed_errors,
         suppressed_warnings,
         debug_config,
+        backend,
+        cache,
+        watch,
+    )?;
+    build.write_to_combined_json(
+        messages,
+        &mut combined_json,
+        metadata_hash_type,
+        append_cbor,
     )?;
-    build.write_to_combined_json(&mut combined_json)?;
+    if profile == era_solc::CombinedJsonProfile::Strict {
+        combined_json.remove_evm_artifacts();
+    }
 
     match output_directory {
         Some(output_directory) => {
             std::fs::create_dir_all(output_directory.as_path())?;
-            combined_json.write_to_directory(output_directory.as_path(), overwrite)?;
+            if per_contract {
+                combined_json
+                    .write_to_directory_per_contract(output_directory.as_path(), overwrite)?;
+            } else {
+                combined_json.write_to_directory(output_directory.as_path(), overwrite)?;
+            }
 
             writeln!(
                 std::io::stderr(),
@@ -548,7 +781,7 @@ pub fn combined_json_eravm(
             serde_json::to_writer(std::io::stdout(), &combined_json)?;
         }
     }
-    std::process::exit(era_compiler_common::EXIT_CODE_SUCCESS);
+    Ok(())
 }
 
 ///
@@ -597,35 +830,76 @@ pub fn disassemble_eravm(paths: Vec<String>) -> anyhow::Result<()> {
 ///
 /// Links EraVM bytecode files.
 ///
-pub fn link_eravm(paths: Vec<String>, libraries: Vec<String>) -> anyhow::Result<()> {
-    let bytecodes = paths
+/// Both hexadecimal and binary files are accepted. Linked files are written back in their input format,
+/// unless `output_format` is specified.
+///
+/// If `link_map_path` is provided, the link map is also written to it.
+/// If `dependency_graph_path` is provided, the factory dependency graph is also written to it.
+///
+pub fn link_eravm(
+    paths: Vec<String>,
+    libraries: Vec<String>,
+    libraries_create2: Vec<String>,
+    output_format: Option<LinkerFileFormat>,
+    link_map_path: Option<PathBuf>,
+    dependency_graph_path: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let files = paths
         .into_par_iter()
         .map(|path| {
-            let bytecode = std::fs::read_to_string(path.as_str())?;
-            Ok((path, bytecode))
+            let data = std::fs::read(path.as_str())
+                .map_err(|error| anyhow::anyhow!("Bytecode file {path:?} reading: {error}"))?;
+            let format = LinkerFileFormat::detect(data.as_slice());
+            Ok((path, (format.hexadecimal_from_file(data), format)))
         })
-        .collect::<anyhow::Result<BTreeMap<String, String>>>()?;
+        .collect::<anyhow::Result<BTreeMap<String, (String, LinkerFileFormat)>>>()?;
+    let formats: BTreeMap<String, LinkerFileFormat> = files
+        .iter()
+        .map(|(path, (_bytecode, format))| (path.to_owned(), output_format.unwrap_or(*format)))
+        .collect();
+    let bytecodes = files
+        .into_iter()
+        .map(|(path, (bytecode, _format))| (path, bytecode))
+        .collect::<BTreeMap<String, String>>();
 
-    let input = LinkerInput::new(bytecodes, libraries);
+    let input = LinkerInput::new(bytecodes, libraries, libraries_create2);
     let output = Linker::link_eravm(input)?;
+    if let Some(link_map_path) = link_map_path {
+        output.write_link_map(link_map_path.as_path())?;
+    }
+    if let Some(dependency_graph_path) = dependency_graph_path {
+        output
+            .dependency_graph
+            .write_to_file(dependency_graph_path.as_path())?;
+    }
 
     output
         .linked
         .par_iter()
         .map(|(path, contract)| {
-            std::fs::write(path, contract.bytecode.as_bytes())?;
+            let format = formats.get(path.as_str()).expect("Always exists");
+            let data = format.file_from_hexadecimal(contract.bytecode.as_str())?;
+            std::fs::write(path, data.as_slice())
+                .map_err(|error| anyhow::anyhow!("Bytecode file {path:?} writing: {error}"))?;
             Ok(())
         })
         .collect::<anyhow::Result<()>>()?;
 
     serde_json::to_writer(std::io::stdout(), &output)?;
-    std::process::exit(era_compiler_common::EXIT_CODE_SUCCESS);
+    std::process::exit(output.exit_code());
 }
 
 ///
 /// Links EraVM bytecode files received as JSON input.
 ///
-pub fn link_eravm_json(path: Option<String>) -> anyhow::Result<()> {
+/// If `link_map_path` is provided, the link map is also written to it.
+/// If `dependency_graph_path` is provided, the factory dependency graph is also written to it.
+///
+pub fn link_eravm_json(
+    path: Option<String>,
+    link_map_path: Option<PathBuf>,
+    dependency_graph_path: Option<PathBuf>,
+) -> anyhow::Result<()> {
     let input_json = match path.map(PathBuf::from) {
         Some(path) => std::fs::read_to_string(path.as_path())
             .map_err(|error| anyhow::anyhow!("JSON file {path:?} reading: {error}")),
@@ -636,7 +910,141 @@ pub fn link_eravm_json(path: Option<String>) -> anyhow::Result<()> {
     let input = era_compiler_common::deserialize_from_str::<LinkerInput>(input_json.as_str())
         .map_err(|error| anyhow::anyhow!("JSON parsing: {error}"))?;
     let output = Linker::link_eravm(input)?;
+    if let Some(link_map_path) = link_map_path {
+        output.write_link_map(link_map_path.as_path())?;
+    }
+    if let Some(dependency_graph_path) = dependency_graph_path {
+        output
+            .dependency_graph
+            .write_to_file(dependency_graph_path.as_path())?;
+    }
 
     serde_json::to_writer(std::io::stdout(), &output)?;
+    std::process::exit(output.exit_code());
+}
+
+///
+/// Links all contracts in a `zksolc` standard JSON output file.
+///
+/// The linked standard JSON output is printed to stdout.
+/// If `link_map_path` is provided, the link map is also written to it.
+/// If `dependency_graph_path` is provided, the factory dependency graph is also written to it.
+///
+pub fn link_eravm_standard_json_output(
+    path: PathBuf,
+    libraries: Vec<String>,
+    libraries_create2: Vec<String>,
+    link_map_path: Option<PathBuf>,
+    dependency_graph_path: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let output_json = std::fs::read_to_string(path.as_path())
+        .map_err(|error| anyhow::anyhow!("Standard JSON output file {path:?} reading: {error}"))?;
+
+    let (standard_json, output) =
+        Linker::link_eravm_standard_json(output_json.as_str(), libraries, libraries_create2)?;
+    if let Some(link_map_path) = link_map_path {
+        output.write_link_map(link_map_path.as_path())?;
+    }
+    if let Some(dependency_graph_path) = dependency_graph_path {
+        output
+            .dependency_graph
+            .write_to_file(dependency_graph_path.as_path())?;
+    }
+
+    serde_json::to_writer(std::io::stdout(), &standard_json)?;
+    std::process::exit(output.exit_code());
+}
+
+///
+/// Inspects unlinked EraVM bytecode files, reporting offsets of library and factory dependency references
+/// in the linked bytecode.
+///
+/// If `linked_path` is provided, the only input file is compared to the linked bytecode in it,
+/// and the values written in place of the references are recovered.
+///
+pub fn inspect_eravm(paths: Vec<String>, linked_path: Option<PathBuf>) -> anyhow::Result<()> {
+    let linked = match linked_path {
+        Some(path) => {
+            let data = std::fs::read(path.as_path())
+                .map_err(|error| anyhow::anyhow!("Bytecode file {path:?} reading: {error}"))?;
+            Some(LinkerFileFormat::binary_from_file(
+                path.to_string_lossy().as_ref(),
+                data,
+            )?)
+        }
+        None => None,
+    };
+
+    let inspections = paths
+        .into_par_iter()
+        .map(|path| {
+            let data = std::fs::read(path.as_str())
+                .map_err(|error| anyhow::anyhow!("Bytecode file {path:?} reading: {error}"))?;
+            let bytecode = LinkerFileFormat::binary_from_file(path.as_str(), data)?;
+            let inspection =
+                LinkerInspection::try_new(path.as_str(), bytecode.as_slice(), linked.as_deref())?;
+            Ok((path, inspection))
+        })
+        .collect::<anyhow::Result<BTreeMap<String, LinkerInspection>>>()?;
+
+    serde_json::to_writer(std::io::stdout(), &inspections)?;
     std::process::exit(era_compiler_common::EXIT_CODE_SUCCESS);
 }
+
+///
+/// Verifies the bytecode file at `bytecode_path` against the project in the standard JSON input.
+///
+/// The project is recompiled, and the verification report is printed to stdout.
+/// If `ignore_metadata` is set, the CBOR metadata at the end of the bytecode is not compared.
+///
+pub fn verify_eravm(
+    bytecode_path: PathBuf,
+    ignore_metadata: bool,
+    solc_compiler: Option<era_solc::Compiler>,
+    codegen: Option<era_solc::StandardJsonInputCodegen>,
+    enable_eravm_extensions: bool,
+    json_path: Option<PathBuf>,
+    messages: &mut Vec<era_solc::StandardJsonOutputError>,
+    base_path: Option<String>,
+    include_paths: Vec<String>,
+    allow_paths: Option<String>,
+    backend: ProcessBackend,
+) -> anyhow::Result<()> {
+    let data = std::fs::read(bytecode_path.as_path())
+        .map_err(|error| anyhow::anyhow!("Bytecode file {bytecode_path:?} reading: {error}"))?;
+    let hexadecimal = LinkerFileFormat::detect(data.as_slice()).hexadecimal_from_file(data);
+    let bytecode = hex::decode(
+        hexadecimal
+            .strip_prefix("0x")
+            .unwrap_or(hexadecimal.as_str()),
+    )
+    .map_err(|error| anyhow::anyhow!("Bytecode file {bytecode_path:?} decoding: {error}"))?;
+
+    let solc_input = era_solc::StandardJsonInput::try_from(json_path.as_deref())?;
+    let solc_output = standard_json_eravm_output(
+        solc_compiler,
+        codegen,
+        enable_eravm_extensions,
+        false,
+        solc_input,
+        messages,
+        base_path,
+        include_paths,
+        allow_paths,
+        None,
+        backend,
+        None,
+    )?;
+    if solc_output.has_errors() {
+        messages.extend(solc_output.errors);
+        anyhow::bail!("Recompilation of the standard JSON input failed.");
+    }
+
+    let report = Verifier::verify(&solc_output, bytecode.as_slice(), ignore_metadata)?;
+    serde_json::to_writer(std::io::stdout(), &report)?;
+    std::process::exit(if report.verified {
+        era_compiler_common::EXIT_CODE_SUCCESS
+    } else {
+        era_compiler_common::EXIT_CODE_FAILURE
+    });
+}
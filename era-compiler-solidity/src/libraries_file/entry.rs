@@ -0,0 +1,20 @@
+//!
+//! The libraries file entry.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The libraries file entry.
+///
+/// Either a library address in the flat `{"<path>:<name>": "<address>"}` format,
+/// or a file section in the nested `solc`-style `{"<path>": {"<name>": "<address>"}}` format.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Entry {
+    /// The library address, with the full library name as the key.
+    Address(String),
+    /// The file section, mapping library names to addresses.
+    File(BTreeMap<String, String>),
+}
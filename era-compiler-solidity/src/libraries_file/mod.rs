@@ -0,0 +1,164 @@
+//!
+//! The libraries file.
+//!
+
+pub mod entry;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use self::entry::Entry;
+
+///
+/// The libraries file.
+///
+/// Contains deployed library addresses, which are otherwise passed via `--libraries`.
+/// Both the nested `solc`-style `{"<path>": {"<name>": "<address>"}}` format and the flat
+/// `{"<path>:<name>": "<address>"}` format are accepted, and can be mixed in a single file.
+///
+#[derive(Debug, Default)]
+pub struct LibrariesFile {
+    /// The library addresses, with full library names as keys.
+    pub libraries: BTreeMap<String, String>,
+}
+
+impl LibrariesFile {
+    ///
+    /// Reads and parses the libraries file.
+    ///
+    /// The TOML format is used for files with the `.toml` extension, and JSON otherwise.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Libraries file {path:?} reading: {error}"))?;
+        let entries: BTreeMap<String, Entry> =
+            if path.extension().and_then(|extension| extension.to_str()) == Some("toml") {
+                toml::from_str(contents.as_str())
+                    .map_err(|error| anyhow::anyhow!("Libraries file {path:?} parsing: {error}"))?
+            } else {
+                serde_json::from_str(contents.as_str())
+                    .map_err(|error| anyhow::anyhow!("Libraries file {path:?} parsing: {error}"))?
+            };
+
+        let mut libraries = BTreeMap::new();
+        for (key, entry) in entries.into_iter() {
+            match entry {
+                Entry::Address(address) => {
+                    libraries.insert(key, address);
+                }
+                Entry::File(file) => {
+                    for (name, address) in file.into_iter() {
+                        libraries.insert(format!("{key}:{name}"), address);
+                    }
+                }
+            }
+        }
+        Ok(Self { libraries })
+    }
+
+    ///
+    /// Returns the libraries in the `<path>:<name>=<address>` format accepted by `--libraries`.
+    ///
+    pub fn to_arguments(&self) -> Vec<String> {
+        self.libraries
+            .iter()
+            .map(|(name, address)| format!("{name}={address}"))
+            .collect()
+    }
+
+    ///
+    /// Returns warnings for addresses failing the EIP-55 checksum,
+    /// and for addresses shared by several libraries.
+    ///
+    /// Collisions are also checked against the `<path>:<name>=<address>` libraries passed inline.
+    ///
+    pub fn warnings(&self, inline_libraries: &[String]) -> Vec<era_solc::StandardJsonOutputError> {
+        let mut warnings = Vec::new();
+
+        let mut address_libraries: BTreeMap<String, BTreeSet<&str>> = BTreeMap::new();
+        for (name, address) in inline_libraries
+            .iter()
+            .filter_map(|library| library.split_once('='))
+        {
+            address_libraries
+                .entry(address.trim().to_lowercase())
+                .or_default()
+                .insert(name.trim());
+        }
+        for (name, address) in self.libraries.iter() {
+            if let Some(checksummed) = Self::checksum_mismatch(address.as_str()) {
+                warnings.push(era_solc::StandardJsonOutputError::new_warning(
+                    format!("Library `{name}` address `{address}` does not match its EIP-55 checksum. Expected `{checksummed}`."),
+                    None,
+                    None,
+                ));
+            }
+            address_libraries
+                .entry(address.to_lowercase())
+                .or_default()
+                .insert(name.as_str());
+        }
+
+        for (address, names) in address_libraries.into_iter() {
+            if names.len() < 2 {
+                continue;
+            }
+            warnings.push(era_solc::StandardJsonOutputError::new_warning(
+                format!(
+                    "Address `{address}` is assigned to several libraries: {}.",
+                    names
+                        .into_iter()
+                        .map(|name| format!("`{name}`"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                None,
+                None,
+            ));
+        }
+
+        warnings
+    }
+
+    ///
+    /// Returns the EIP-55 checksummed address if `address` is in mixed case and its checksum is wrong.
+    ///
+    /// All-lowercase and all-uppercase addresses carry no checksum and are not checked.
+    /// Malformed addresses are left to the libraries parser to report.
+    ///
+    fn checksum_mismatch(address: &str) -> Option<String> {
+        let digits = address.strip_prefix("0x")?;
+        if digits.len() != era_compiler_common::BYTE_LENGTH_ETH_ADDRESS * 2
+            || !digits
+                .chars()
+                .all(|character| character.is_ascii_hexdigit())
+        {
+            return None;
+        }
+        if digits == digits.to_lowercase() || digits == digits.to_uppercase() {
+            return None;
+        }
+
+        let lowercase = digits.to_lowercase();
+        let hash = era_compiler_common::Keccak256Hash::from_slice(lowercase.as_bytes()).to_string();
+        let hash = hash.trim_start_matches("0x");
+        let checksummed = lowercase
+            .chars()
+            .zip(hash.chars())
+            .map(|(character, nibble)| {
+                if nibble.to_digit(16).expect("Always valid") >= 8 {
+                    character.to_ascii_uppercase()
+                } else {
+                    character
+                }
+            })
+            .collect::<String>();
+
+        if checksummed == digits {
+            None
+        } else {
+            Some(format!("0x{checksummed}"))
+        }
+    }
+}
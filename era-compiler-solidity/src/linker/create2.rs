@@ -0,0 +1,118 @@
+//!
+//! The linker CREATE2 library address parameters.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The linker CREATE2 library address parameters.
+///
+/// The library address is not known in advance, but follows from the deployer, the salt,
+/// and the bytecode hash of the linked library.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Create2 {
+    /// The deployer address.
+    pub deployer: [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS],
+    /// The salt.
+    pub salt: [u8; era_compiler_common::BYTE_LENGTH_FIELD],
+}
+
+impl Create2 {
+    /// The EraVM CREATE2 address derivation prefix preimage.
+    pub const PREFIX_PREIMAGE: &'static [u8] = b"zksyncCreate2";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        deployer: [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS],
+        salt: [u8; era_compiler_common::BYTE_LENGTH_FIELD],
+    ) -> Self {
+        Self { deployer, salt }
+    }
+
+    ///
+    /// Parses CREATE2 library specifiers in format `<path>:<library>=<deployer>:<salt>`.
+    ///
+    pub fn try_from_specifiers(specifiers: &[String]) -> anyhow::Result<BTreeMap<String, Self>> {
+        let mut libraries = BTreeMap::new();
+        for specifier in specifiers.iter() {
+            let (library, parameters) = specifier.rsplit_once('=').ok_or_else(|| {
+                anyhow::anyhow!("CREATE2 library `{specifier}` parameters are missing.")
+            })?;
+            if !library.contains(':') {
+                anyhow::bail!("CREATE2 library `{library}` contract name is missing.");
+            }
+            let (deployer, salt) = parameters.split_once(':').ok_or_else(|| {
+                anyhow::anyhow!(
+                    "CREATE2 library `{library}` parameters must be specified as `<deployer>:<salt>`."
+                )
+            })?;
+
+            let deployer = Self::decode(deployer).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid deployer address `{deployer}` of CREATE2 library `{library}`."
+                )
+            })?;
+            let salt = Self::decode(salt).ok_or_else(|| {
+                anyhow::anyhow!("Invalid salt `{salt}` of CREATE2 library `{library}`.")
+            })?;
+            if libraries
+                .insert(library.to_owned(), Self::new(deployer, salt))
+                .is_some()
+            {
+                anyhow::bail!("CREATE2 library `{library}` is specified more than once.");
+            }
+        }
+        Ok(libraries)
+    }
+
+    ///
+    /// Computes the EraVM CREATE2 address of a library with `bytecode_hash` and empty constructor input.
+    ///
+    /// The address is the last 20 bytes of
+    /// `keccak256(keccak256("zksyncCreate2") ++ deployer ++ salt ++ bytecode_hash ++ keccak256(input))`,
+    /// where the deployer address is left-padded to 32 bytes.
+    ///
+    pub fn address(
+        &self,
+        bytecode_hash: &[u8; era_compiler_common::BYTE_LENGTH_FIELD],
+    ) -> [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS] {
+        let mut preimage = Vec::with_capacity(era_compiler_common::BYTE_LENGTH_FIELD * 5);
+        preimage.extend(Self::keccak256(Self::PREFIX_PREIMAGE));
+        preimage.extend(
+            [0u8; era_compiler_common::BYTE_LENGTH_FIELD
+                - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS],
+        );
+        preimage.extend(self.deployer);
+        preimage.extend(self.salt);
+        preimage.extend(bytecode_hash);
+        preimage.extend(Self::keccak256(&[]));
+
+        let hash = Self::keccak256(preimage.as_slice());
+        hash[era_compiler_common::BYTE_LENGTH_FIELD
+            - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS..]
+            .try_into()
+            .expect("Always valid")
+    }
+
+    ///
+    /// Decodes a hexadecimal string prefixed with `0x` into a fixed-size array.
+    ///
+    fn decode<const N: usize>(string: &str) -> Option<[u8; N]> {
+        let string = string.strip_prefix("0x")?;
+        hex::decode(string).ok()?.try_into().ok()
+    }
+
+    ///
+    /// Computes the `keccak256` hash of `data`.
+    ///
+    fn keccak256(data: &[u8]) -> [u8; era_compiler_common::BYTE_LENGTH_FIELD] {
+        let hash = era_compiler_common::Keccak256Hash::from_slice(data).to_string();
+        hex::decode(hash.trim_start_matches("0x"))
+            .expect("Always valid")
+            .try_into()
+            .expect("Always valid")
+    }
+}
@@ -0,0 +1,105 @@
+//!
+//! The linker bytecode file format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The linker bytecode file format.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileFormat {
+    /// The hexadecimal string, optionally prefixed with `0x`.
+    Hexadecimal,
+    /// The raw binary, such as ELF objects or raw EraVM bytecode (*.zbin).
+    Binary,
+}
+
+impl FileFormat {
+    /// The ELF file magic number.
+    pub const ELF_MAGIC: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+
+    ///
+    /// Detects the format of the bytecode file `data`.
+    ///
+    /// ELF objects are always binary. Other files are considered hexadecimal if they only
+    /// consist of hexadecimal digits and whitespace, and raw binary otherwise.
+    ///
+    pub fn detect(data: &[u8]) -> Self {
+        if data.starts_with(Self::ELF_MAGIC.as_slice()) {
+            return Self::Binary;
+        }
+
+        let data = data.strip_prefix(b"0x").unwrap_or(data);
+        if data
+            .iter()
+            .all(|byte| byte.is_ascii_hexdigit() || byte.is_ascii_whitespace())
+        {
+            Self::Hexadecimal
+        } else {
+            Self::Binary
+        }
+    }
+
+    ///
+    /// Converts the bytecode file `data` to a hexadecimal string accepted by the linker.
+    ///
+    pub fn hexadecimal_from_file(self, data: Vec<u8>) -> String {
+        match self {
+            Self::Hexadecimal => String::from_utf8_lossy(data.as_slice()).trim().to_owned(),
+            Self::Binary => hex::encode(data),
+        }
+    }
+
+    ///
+    /// Converts the bytecode file `data` of any format to raw bytes.
+    ///
+    pub fn binary_from_file(path: &str, data: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+        let hexadecimal = Self::detect(data.as_slice()).hexadecimal_from_file(data);
+        hex::decode(
+            hexadecimal
+                .strip_prefix("0x")
+                .unwrap_or(hexadecimal.as_str()),
+        )
+        .map_err(|error| anyhow::anyhow!("Object `{path}` hexadecimal string decoding: {error}"))
+    }
+
+    ///
+    /// Converts the linker `hexadecimal` output back to the file contents.
+    ///
+    pub fn file_from_hexadecimal(self, hexadecimal: &str) -> anyhow::Result<Vec<u8>> {
+        match self {
+            Self::Hexadecimal => Ok(hexadecimal.as_bytes().to_vec()),
+            Self::Binary => hex::decode(hexadecimal)
+                .map_err(|error| anyhow::anyhow!("Linked bytecode decoding: {error}")),
+        }
+    }
+}
+
+impl FromStr for FileFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "hex" => Ok(Self::Hexadecimal),
+            "binary" => Ok(Self::Binary),
+            string => anyhow::bail!(
+                "Invalid linker output format: `{string}`. Available options: {}.",
+                [Self::Hexadecimal, Self::Binary]
+                    .into_iter()
+                    .map(|format| format.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for FileFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Hexadecimal => write!(f, "hex"),
+            Self::Binary => write!(f, "binary"),
+        }
+    }
+}
@@ -13,16 +13,24 @@ pub struct Input {
     pub bytecodes: BTreeMap<String, String>,
     /// Library linking specifiers in format `<path>:<library>=<address>`.
     pub libraries: Vec<String>,
+    /// CREATE2 library specifiers in format `<path>:<library>=<deployer>:<salt>`.
+    #[serde(default)]
+    pub libraries_create2: Vec<String>,
 }
 
 impl Input {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(bytecodes: BTreeMap<String, String>, libraries: Vec<String>) -> Self {
+    pub fn new(
+        bytecodes: BTreeMap<String, String>,
+        libraries: Vec<String>,
+        libraries_create2: Vec<String>,
+    ) -> Self {
         Self {
             bytecodes,
             libraries,
+            libraries_create2,
         }
     }
 }
@@ -0,0 +1,303 @@
+//!
+//! The linker inspection.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The link references, as a mapping from file paths to contract names to reference lists.
+///
+pub type LinkReferences =
+    BTreeMap<String, BTreeMap<String, Vec<era_solc::StandardJsonOutputContractEraVMLinkReference>>>;
+
+///
+/// The linker inspection.
+///
+/// Describes where library addresses and factory dependency hashes are written in the linked bytecode.
+///
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Inspection {
+    /// The linked bytecode with all references filled with zeros.
+    pub bytecode: String,
+    /// The library address references.
+    pub link_references: LinkReferences,
+    /// The factory dependency hash references.
+    pub factory_dependency_references: LinkReferences,
+    /// The library addresses recovered from the linked bytecode.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub linker_symbols: BTreeMap<String, String>,
+    /// The factory dependency hashes recovered from the linked bytecode.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub factory_dependencies: BTreeMap<String, String>,
+    /// Whether the linked bytecode only differs from the unlinked one in the references.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_matching: Option<bool>,
+}
+
+impl Inspection {
+    /// The library address placeholder filler byte.
+    pub const LINKER_SYMBOL_FILLER: u8 = 0xAA;
+
+    /// The factory dependency hash placeholder filler byte.
+    pub const FACTORY_DEPENDENCY_FILLER: u8 = 0xCC;
+
+    ///
+    /// Inspects the unlinked ELF object `unlinked`, and optionally the `linked` bytecode produced from it.
+    ///
+    /// The object is linked twice with placeholders that differ in every byte, so the references
+    /// are the ranges where the results differ. The placeholders are unique for each symbol,
+    /// which allows telling the references apart.
+    ///
+    pub fn try_new(path: &str, unlinked: &[u8], linked: Option<&[u8]>) -> anyhow::Result<Self> {
+        let memory_buffer =
+            inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(unlinked, path, false);
+        if !memory_buffer.is_elf_eravm() {
+            anyhow::bail!("Object `{path}` is not an unlinked ELF object.");
+        }
+        let (linker_symbols, factory_dependencies) = memory_buffer.get_undefined_references_eravm();
+
+        let mut placeholders = Vec::with_capacity(2);
+        for is_inverted in [false, true] {
+            let linker_symbol_placeholders: BTreeMap<
+                String,
+                [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS],
+            > = linker_symbols
+                .iter()
+                .enumerate()
+                .map(|(index, symbol)| {
+                    (
+                        symbol.to_owned(),
+                        Self::placeholder(index, Self::LINKER_SYMBOL_FILLER, is_inverted),
+                    )
+                })
+                .collect();
+            let factory_dependency_placeholders: BTreeMap<
+                String,
+                [u8; era_compiler_common::BYTE_LENGTH_FIELD],
+            > = factory_dependencies
+                .iter()
+                .enumerate()
+                .map(|(index, dependency)| {
+                    (
+                        dependency.to_owned(),
+                        Self::placeholder(index, Self::FACTORY_DEPENDENCY_FILLER, is_inverted),
+                    )
+                })
+                .collect();
+
+            let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
+                unlinked, path, false,
+            );
+            let (memory_buffer_linked, object_format) = era_compiler_llvm_context::eravm_link(
+                memory_buffer,
+                &linker_symbol_placeholders,
+                &factory_dependency_placeholders,
+            )?;
+            if object_format != era_compiler_common::ObjectFormat::Raw {
+                anyhow::bail!("Object `{path}` cannot be linked with placeholders.");
+            }
+            placeholders.push(memory_buffer_linked.as_slice().to_vec());
+        }
+        let inverted = placeholders.pop().expect("Always exists");
+        let mut bytecode = placeholders.pop().expect("Always exists");
+        if bytecode.len() != inverted.len() {
+            anyhow::bail!("Object `{path}` size depends on the linked values.");
+        }
+
+        let mut inspection = Self::default();
+        let mut references = Vec::new();
+        let mut offset = 0;
+        while offset < bytecode.len() {
+            if bytecode[offset] == inverted[offset] {
+                offset += 1;
+                continue;
+            }
+
+            let factory_dependency = factory_dependencies.iter().enumerate().find(|(index, _)| {
+                Self::matches::<{ era_compiler_common::BYTE_LENGTH_FIELD }>(
+                    &bytecode,
+                    &inverted,
+                    offset,
+                    *index,
+                    Self::FACTORY_DEPENDENCY_FILLER,
+                )
+            });
+            let linker_symbol = linker_symbols.iter().enumerate().find(|(index, _)| {
+                Self::matches::<{ era_compiler_common::BYTE_LENGTH_ETH_ADDRESS }>(
+                    &bytecode,
+                    &inverted,
+                    offset,
+                    *index,
+                    Self::LINKER_SYMBOL_FILLER,
+                )
+            });
+            let (symbol, length, is_linker_symbol) = match (factory_dependency, linker_symbol) {
+                (Some((_, dependency)), _) => {
+                    (dependency, era_compiler_common::BYTE_LENGTH_FIELD, false)
+                }
+                (None, Some((_, symbol))) => {
+                    (symbol, era_compiler_common::BYTE_LENGTH_ETH_ADDRESS, true)
+                }
+                (None, None) => {
+                    anyhow::bail!(
+                        "Object `{path}` has an unrecognized reference at offset {offset}."
+                    )
+                }
+            };
+
+            let (file, name) = symbol.rsplit_once(':').unwrap_or((symbol.as_str(), ""));
+            let target = if is_linker_symbol {
+                &mut inspection.link_references
+            } else {
+                &mut inspection.factory_dependency_references
+            };
+            target
+                .entry(file.to_owned())
+                .or_default()
+                .entry(name.to_owned())
+                .or_default()
+                .push(era_solc::StandardJsonOutputContractEraVMLinkReference::new(
+                    offset, length,
+                ));
+            references.push((symbol.to_owned(), offset, length, is_linker_symbol));
+            offset += length;
+        }
+
+        for (_, offset, length, _) in references.iter() {
+            bytecode[*offset..*offset + *length].fill(0);
+        }
+
+        if let Some(linked) = linked {
+            let mut linked_template = linked.to_vec();
+            for (symbol, offset, length, is_linker_symbol) in references.iter() {
+                let Some(value) = linked.get(*offset..*offset + *length) else {
+                    continue;
+                };
+                let (target, value) = if *is_linker_symbol {
+                    (
+                        &mut inspection.linker_symbols,
+                        format!("0x{}", hex::encode(value)),
+                    )
+                } else {
+                    (&mut inspection.factory_dependencies, hex::encode(value))
+                };
+                if let Some(previous) = target.insert(symbol.to_owned(), value.clone()) {
+                    if previous != value {
+                        anyhow::bail!("Reference `{symbol}` has different values in the linked bytecode: `{previous}` and `{value}`.");
+                    }
+                }
+                linked_template[*offset..*offset + *length].fill(0);
+            }
+            inspection.is_matching = Some(linked_template == bytecode);
+        }
+
+        inspection.bytecode = hex::encode(bytecode);
+        Ok(inspection)
+    }
+
+    ///
+    /// Writes the references of all contracts to the standard JSON.
+    ///
+    /// Contracts that cannot be inspected are reported with warnings and left without references.
+    ///
+    pub fn write_to_standard_json(
+        standard_json: &mut era_solc::StandardJsonOutput,
+        output_link_references: bool,
+        output_factory_dependency_references: bool,
+    ) {
+        if !output_link_references && !output_factory_dependency_references {
+            return;
+        }
+
+        for (path, contracts) in standard_json.contracts.iter_mut() {
+            for (name, contract) in contracts.iter_mut() {
+                if let Err(error) = Self::write_to_standard_json_contract(
+                    format!("{path}:{name}").as_str(),
+                    contract,
+                    output_link_references,
+                    output_factory_dependency_references,
+                ) {
+                    standard_json
+                        .errors
+                        .push(era_solc::StandardJsonOutputError::new_warning(
+                            format!("Contract `{path}:{name}` references inspection: {error}"),
+                            None,
+                            None,
+                        ));
+                }
+            }
+        }
+    }
+
+    ///
+    /// Writes the references of the contract to the standard JSON contract.
+    ///
+    /// Linked contracts get empty references and no template.
+    ///
+    pub fn write_to_standard_json_contract(
+        full_path: &str,
+        standard_json_contract: &mut era_solc::StandardJsonOutputContract,
+        output_link_references: bool,
+        output_factory_dependency_references: bool,
+    ) -> anyhow::Result<()> {
+        let is_elf =
+            standard_json_contract.object_format == Some(era_compiler_common::ObjectFormat::ELF);
+        let Some(eravm) = standard_json_contract.eravm.as_mut() else {
+            return Ok(());
+        };
+        eravm.link_references = None;
+        eravm.factory_dependency_references = None;
+        eravm.bytecode_template = None;
+        if !output_link_references && !output_factory_dependency_references {
+            return Ok(());
+        }
+
+        let inspection = if is_elf {
+            let bytecode = hex::decode(eravm.bytecode.as_str())
+                .map_err(|error| anyhow::anyhow!("Bytecode decoding: {error}"))?;
+            let inspection = Self::try_new(full_path, bytecode.as_slice(), None)?;
+            eravm.bytecode_template = Some(inspection.bytecode.clone());
+            inspection
+        } else {
+            Self::default()
+        };
+        if output_link_references {
+            eravm.link_references = Some(inspection.link_references);
+        }
+        if output_factory_dependency_references {
+            eravm.factory_dependency_references = Some(inspection.factory_dependency_references);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the placeholder of the symbol at `index`.
+    ///
+    /// The inverted placeholder differs from the original one in every byte.
+    ///
+    fn placeholder<const N: usize>(index: usize, filler: u8, is_inverted: bool) -> [u8; N] {
+        let mut placeholder = [filler; N];
+        placeholder[..std::mem::size_of::<u64>()].copy_from_slice(&(index as u64).to_be_bytes());
+        if is_inverted {
+            placeholder.iter_mut().for_each(|byte| *byte = !*byte);
+        }
+        placeholder
+    }
+
+    ///
+    /// Checks whether both placeholders of the symbol at `index` are written at `offset`.
+    ///
+    fn matches<const N: usize>(
+        bytecode: &[u8],
+        inverted: &[u8],
+        offset: usize,
+        index: usize,
+        filler: u8,
+    ) -> bool {
+        bytecode.get(offset..offset + N)
+            == Some(Self::placeholder::<N>(index, filler, false).as_slice())
+            && inverted.get(offset..offset + N)
+                == Some(Self::placeholder::<N>(index, filler, true).as_slice())
+    }
+}
@@ -2,13 +2,23 @@
 //! The linker.
 //!
 
+pub mod create2;
+pub mod file_format;
 pub mod input;
+pub mod inspection;
 pub mod output;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 
+use crate::build_eravm::contract::Contract as EraVMContractBuild;
+
+use self::create2::Create2;
 use self::input::Input;
+use self::inspection::Inspection;
 use self::output::ignored::Ignored as OutputIgnored;
+use self::output::link_map::Object as OutputLinkMapObject;
 use self::output::linked::Linked as OutputLinked;
 use self::output::unlinked::Unlinked as OutputUnlinked;
 use self::output::Output;
@@ -23,9 +33,21 @@ impl Linker {
     ///
     /// Links EraVM bytecode files.
     ///
+    /// Addresses of CREATE2 libraries are computed as soon as the libraries are linked,
+    /// so their dependents are linked in the following iterations.
+    ///
     pub fn link_eravm(input: Input) -> anyhow::Result<Output> {
-        let linker_symbols = era_compiler_common::Libraries::try_from(input.libraries.as_slice())?
-            .as_linker_symbols()?;
+        let mut linker_symbols =
+            era_compiler_common::Libraries::try_from(input.libraries.as_slice())?
+                .as_linker_symbols()?;
+        let mut create2_libraries =
+            Create2::try_from_specifiers(input.libraries_create2.as_slice())?;
+        if let Some(library) = create2_libraries
+            .keys()
+            .find(|library| linker_symbols.contains_key(library.as_str()))
+        {
+            anyhow::bail!("Library `{library}` is specified both with an address and with CREATE2 parameters.");
+        }
         let mut output = Output::default();
         let mut unlinked_objects = Vec::new();
         let mut factory_dependencies = BTreeMap::new();
@@ -61,10 +83,38 @@ impl Linker {
                 path.clone(),
                 OutputIgnored::new(bytecode_string, hex::encode(hash)),
             );
+            output.link_map.insert(
+                path.clone(),
+                OutputLinkMapObject::new_ignored(hex::encode(hash)),
+            );
+            output
+                .dependency_graph
+                .add(path.clone(), true, Default::default());
             factory_dependencies.insert(path, hash);
         }
 
+        // the references are taken before linking, as some of them may be resolved in earlier iterations
+        let initial_references: BTreeMap<String, (Vec<String>, Vec<String>)> = unlinked_objects
+            .iter()
+            .map(|(path, bytecode_buffer)| {
+                (
+                    path.to_owned(),
+                    bytecode_buffer.get_undefined_references_eravm(),
+                )
+            })
+            .collect();
+
+        let mut iteration = 0;
         loop {
+            create2_libraries.retain(|library, create2| {
+                let Some(hash) = factory_dependencies.get(library.as_str()) else {
+                    return true;
+                };
+                linker_symbols.insert(library.to_owned(), create2.address(hash));
+                false
+            });
+
+            iteration += 1;
             let mut linked_counter = 0;
             let mut remaining_objects = Vec::new();
             for (path, bytecode_buffer) in unlinked_objects.drain(..) {
@@ -86,6 +136,34 @@ impl Linker {
                             era_compiler_llvm_context::eravm_hash(&bytecode_buffer_after_linking)
                                 .expect("Always valid");
 
+                        let (initial_linker_symbols, initial_factory_dependencies) =
+                            initial_references
+                                .get(path.as_str())
+                                .expect("Always exists");
+                        let resolved_linker_symbols = initial_linker_symbols
+                            .iter()
+                            .filter_map(|symbol| {
+                                let address = linker_symbols.get(symbol)?;
+                                Some((symbol.to_owned(), format!("0x{}", hex::encode(address))))
+                            })
+                            .collect();
+                        let resolved_factory_dependencies = initial_factory_dependencies
+                            .iter()
+                            .filter_map(|dependency| {
+                                let hash = factory_dependencies.get(dependency)?;
+                                Some((dependency.to_owned(), hex::encode(hash)))
+                            })
+                            .collect();
+                        output.link_map.insert(
+                            path.clone(),
+                            OutputLinkMapObject::new_linked(
+                                iteration,
+                                hex::encode(hash.as_slice()),
+                                resolved_linker_symbols,
+                                resolved_factory_dependencies,
+                            ),
+                        );
+
                         output.linked.insert(
                             path.clone(),
                             OutputLinked::new(
@@ -116,6 +194,196 @@ impl Linker {
                 (path, unlinked)
             })
             .collect();
+        for path in output.unlinked.keys() {
+            output
+                .link_map
+                .insert(path.to_owned(), OutputLinkMapObject::new_unlinked());
+        }
+
+        for (path, (_linker_symbols, factory_dependencies)) in initial_references.into_iter() {
+            let is_linked = output.linked.contains_key(path.as_str());
+            output.dependency_graph.add(
+                path,
+                is_linked,
+                factory_dependencies.into_iter().collect(),
+            );
+        }
+        output.errors = output.dependency_graph.errors();
+        for library in create2_libraries.into_keys() {
+            output.errors.push(era_solc::StandardJsonOutputError::new_error(
+                format!("CREATE2 library `{library}` is not found among the linked input objects, so its address cannot be computed."),
+                None,
+                None,
+            ));
+        }
         Ok(output)
     }
+
+    ///
+    /// Links all contracts in a `zksolc` standard JSON output.
+    ///
+    /// Returns the standard JSON output with linked bytecode, hashes, and factory dependencies,
+    /// along with the linker output describing the process.
+    ///
+    pub fn link_eravm_standard_json(
+        output_json: &str,
+        libraries: Vec<String>,
+        libraries_create2: Vec<String>,
+    ) -> anyhow::Result<(era_solc::StandardJsonOutput, Output)> {
+        let mut standard_json: era_solc::StandardJsonOutput =
+            era_compiler_common::deserialize_from_str(output_json)
+                .map_err(|error| anyhow::anyhow!("Standard JSON output parsing: {error}"))?;
+
+        let mut bytecodes = BTreeMap::new();
+        for (path, file) in standard_json.contracts.iter() {
+            for (name, contract) in file.iter() {
+                let bytecode = match contract.evm.as_ref().and_then(|evm| evm.bytecode.as_ref()) {
+                    Some(bytecode) if !bytecode.object.is_empty() => bytecode.object.to_owned(),
+                    _ => continue,
+                };
+                bytecodes.insert(format!("{path}:{name}"), bytecode);
+            }
+        }
+
+        let mut output =
+            Self::link_eravm(Input::new(bytecodes.clone(), libraries, libraries_create2))?;
+
+        for (path, file) in standard_json.contracts.iter_mut() {
+            for (name, standard_json_contract) in file.iter_mut() {
+                let full_path = format!("{path}:{name}");
+                let Some(bytecode) = bytecodes.get(full_path.as_str()) else {
+                    continue;
+                };
+                let resolved = &standard_json_contract.factory_dependencies;
+
+                let mut missing_libraries = BTreeSet::new();
+                let mut factory_dependencies = BTreeSet::new();
+                let mut factory_dependencies_resolved = HashMap::new();
+                let (bytecode, bytecode_hash, object_format) =
+                    if let Some(linked) = output.linked.get(full_path.as_str()) {
+                        let link_map_object = output
+                            .link_map
+                            .get(full_path.as_str())
+                            .expect("Always exists");
+                        for (dependency, hash) in link_map_object.factory_dependencies.iter() {
+                            factory_dependencies_resolved
+                                .insert(Self::decode_hash(hash.as_str())?, dependency.to_owned());
+                        }
+                        (
+                            linked.bytecode.as_str(),
+                            Some(Self::decode_hash(linked.hash.as_str())?),
+                            era_compiler_common::ObjectFormat::Raw,
+                        )
+                    } else if let Some(ignored) = output.ignored.get(full_path.as_str()) {
+                        for (hash, dependency) in resolved.iter() {
+                            factory_dependencies_resolved
+                                .insert(Self::decode_hash(hash.as_str())?, dependency.to_owned());
+                        }
+                        (
+                            ignored.bytecode.as_str(),
+                            Some(Self::decode_hash(ignored.hash.as_str())?),
+                            era_compiler_common::ObjectFormat::Raw,
+                        )
+                    } else {
+                        let unlinked = output
+                            .unlinked
+                            .get(full_path.as_str())
+                            .expect("Always exists");
+                        missing_libraries.extend(unlinked.linker_symbols.iter().cloned());
+                        factory_dependencies.extend(unlinked.factory_dependencies.iter().cloned());
+                        (
+                            bytecode.as_str(),
+                            None,
+                            era_compiler_common::ObjectFormat::ELF,
+                        )
+                    };
+
+                let bytecode = hex::decode(bytecode.strip_prefix("0x").unwrap_or(bytecode))
+                    .map_err(|error| {
+                        anyhow::anyhow!("Contract `{full_path}` bytecode decoding: {error}")
+                    })?;
+                let eravm = standard_json_contract.eravm.take();
+                let assembly = eravm.as_ref().and_then(|eravm| eravm.assembly.clone());
+                let mut contract = EraVMContractBuild::new(
+                    era_compiler_common::ContractName::new(path.to_owned(), Some(name.to_owned())),
+                    era_compiler_llvm_context::EraVMBuild::new(bytecode, bytecode_hash, assembly),
+                    standard_json_contract.metadata.take(),
+                    missing_libraries,
+                    factory_dependencies,
+                    object_format,
+                );
+                contract.factory_dependencies_resolved = factory_dependencies_resolved;
+                contract.is_size_fallback = eravm
+                    .as_ref()
+                    .map(|eravm| eravm.sizes.size_fallback)
+                    .unwrap_or_default();
+
+                if contract.object_format == era_compiler_common::ObjectFormat::Raw {
+                    if let Err(error) = contract.check_size() {
+                        output
+                            .errors
+                            .push(era_solc::StandardJsonOutputError::new_error(
+                                format!("Contract `{full_path}`: {error}"),
+                                Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+                                    path.to_owned(),
+                                )),
+                                None,
+                            ));
+                    }
+                    if let Err(error) = contract.check_size_limit() {
+                        output
+                            .errors
+                            .push(era_solc::StandardJsonOutputError::new_warning(
+                                format!("Contract `{full_path}`: {error}"),
+                                Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+                                    path.to_owned(),
+                                )),
+                                None,
+                            ));
+                    }
+                }
+                contract.write_to_standard_json(standard_json_contract)?;
+
+                // only the bytecode-dependent fields are replaced, so the rest of the EraVM data survives linking
+                if let Some(mut eravm) = eravm {
+                    let linked = standard_json_contract.eravm.take().expect("Always exists");
+                    eravm.bytecode = linked.bytecode;
+                    eravm.sizes = linked.sizes;
+                    let output_link_references = eravm.link_references.is_some();
+                    let output_factory_dependency_references =
+                        eravm.factory_dependency_references.is_some();
+                    standard_json_contract.eravm = Some(eravm);
+
+                    // the references are only refreshed if they were requested from the compiler
+                    if let Err(error) = Inspection::write_to_standard_json_contract(
+                        full_path.as_str(),
+                        standard_json_contract,
+                        output_link_references,
+                        output_factory_dependency_references,
+                    ) {
+                        output
+                            .errors
+                            .push(era_solc::StandardJsonOutputError::new_warning(
+                                format!("Contract `{full_path}` references inspection: {error}"),
+                                None,
+                                None,
+                            ));
+                    }
+                }
+            }
+        }
+        standard_json.errors.extend(output.errors.iter().cloned());
+
+        Ok((standard_json, output))
+    }
+
+    ///
+    /// Decodes a hexadecimal bytecode hash.
+    ///
+    fn decode_hash(hash: &str) -> anyhow::Result<[u8; era_compiler_common::BYTE_LENGTH_FIELD]> {
+        hex::decode(hash)
+            .ok()
+            .and_then(|hash| hash.try_into().ok())
+            .ok_or_else(|| anyhow::anyhow!("Invalid bytecode hash `{hash}`"))
+    }
 }
@@ -0,0 +1,77 @@
+//!
+//! The link map object.
+//!
+
+pub mod status;
+
+use std::collections::BTreeMap;
+
+use self::status::Status;
+
+///
+/// The link map object.
+///
+/// Describes what was written into the final bytecode of an input object.
+///
+#[derive(Debug, serde::Serialize)]
+pub struct Object {
+    /// The linking result.
+    pub status: Status,
+    /// The 1-based linking iteration the object was linked at.
+    /// Objects are linked in iterations, as each one depends on the hashes of objects linked before.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iteration: Option<usize>,
+    /// The final bytecode hash.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// The resolved library symbols mapped to their addresses.
+    pub linker_symbols: BTreeMap<String, String>,
+    /// The resolved factory dependencies mapped to their bytecode hashes.
+    pub factory_dependencies: BTreeMap<String, String>,
+}
+
+impl Object {
+    ///
+    /// A shortcut constructor for a linked object.
+    ///
+    pub fn new_linked(
+        iteration: usize,
+        hash: String,
+        linker_symbols: BTreeMap<String, String>,
+        factory_dependencies: BTreeMap<String, String>,
+    ) -> Self {
+        Self {
+            status: Status::Linked,
+            iteration: Some(iteration),
+            hash: Some(hash),
+            linker_symbols,
+            factory_dependencies,
+        }
+    }
+
+    ///
+    /// A shortcut constructor for an unlinked object.
+    ///
+    pub fn new_unlinked() -> Self {
+        Self {
+            status: Status::Unlinked,
+            iteration: None,
+            hash: None,
+            linker_symbols: BTreeMap::new(),
+            factory_dependencies: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// A shortcut constructor for an ignored object.
+    ///
+    pub fn new_ignored(hash: String) -> Self {
+        Self {
+            status: Status::Ignored,
+            iteration: None,
+            hash: Some(hash),
+            linker_symbols: BTreeMap::new(),
+            factory_dependencies: BTreeMap::new(),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//!
+//! The link map object status.
+//!
+
+///
+/// The link map object status.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// All references have been resolved, and the ELF wrapper has been stripped.
+    Linked,
+    /// Some references are still unresolved.
+    Unlinked,
+    /// The object is raw bytecode that does not require linking.
+    Ignored,
+}
@@ -3,12 +3,17 @@
 //!
 
 pub mod ignored;
+pub mod link_map;
 pub mod linked;
 pub mod unlinked;
 
 use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::dependency_graph::DependencyGraph;
 
 use self::ignored::Ignored;
+use self::link_map::Object as LinkMapObject;
 use self::linked::Linked;
 use self::unlinked::Unlinked;
 
@@ -23,4 +28,35 @@ pub struct Output {
     pub unlinked: BTreeMap<String, Unlinked>,
     /// Ignored bytecode files that do not require linking.
     pub ignored: BTreeMap<String, Ignored>,
+    /// The description of what was resolved in each input bytecode file.
+    #[serde(rename = "linkMap")]
+    pub link_map: BTreeMap<String, LinkMapObject>,
+    /// The errors explaining why objects could not be linked, such as factory dependency cycles.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<era_solc::StandardJsonOutputError>,
+    /// The factory dependency graph of the input objects.
+    #[serde(skip)]
+    pub dependency_graph: DependencyGraph,
+}
+
+impl Output {
+    ///
+    /// Returns the process exit code, which is a failure if any errors have been reported.
+    ///
+    pub fn exit_code(&self) -> i32 {
+        if self.errors.iter().any(|error| error.severity == "error") {
+            era_compiler_common::EXIT_CODE_FAILURE
+        } else {
+            era_compiler_common::EXIT_CODE_SUCCESS
+        }
+    }
+
+    ///
+    /// Writes the link map to the file at `path`.
+    ///
+    pub fn write_link_map(&self, path: &Path) -> anyhow::Result<()> {
+        let link_map = serde_json::to_vec_pretty(&self.link_map).expect("Always valid");
+        std::fs::write(path, link_map.as_slice())
+            .map_err(|error| anyhow::anyhow!("Link map file {path:?} writing: {error}"))
+    }
 }
@@ -4,21 +4,99 @@
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::collections::VecDeque;
 
 ///
 /// The missing libraries.
 ///
 pub struct MissingLibraries {
-    /// The libraries.
+    /// The libraries referenced by contracts directly.
     pub contract_libraries: BTreeMap<String, BTreeSet<String>>,
+    /// The details of libraries required by contracts, including the ones required through other libraries.
+    ///
+    /// The keys are a superset of `contract_libraries`: the directly referenced libraries have
+    /// single-element chains, and the other ones are only required through them.
+    pub contract_details:
+        BTreeMap<String, BTreeMap<String, era_solc::StandardJsonOutputContractMissingLibrary>>,
 }
 
 impl MissingLibraries {
     ///
-    /// A shortcut constructor.
+    /// Resolves the missing libraries from the direct library references of each contract.
+    ///
+    /// If a missing library is a project contract itself, its own missing libraries are required
+    /// as well, so they are followed transitively. The shortest chain to each library is reported.
+    /// The `deployed_libraries` are not missing, so they are skipped along with their dependencies.
     ///
-    pub fn new(contract_libraries: BTreeMap<String, BTreeSet<String>>) -> Self {
-        Self { contract_libraries }
+    pub fn new(
+        contract_references: BTreeMap<
+            String,
+            BTreeMap<String, Vec<era_solc::StandardJsonOutputContractMissingLibraryLocation>>,
+        >,
+        deployed_libraries: &BTreeSet<String>,
+    ) -> Self {
+        let mut contract_details = BTreeMap::new();
+        for (path, references) in contract_references.iter() {
+            let mut details = BTreeMap::new();
+
+            let mut queue = references
+                .iter()
+                .map(|(library, locations)| {
+                    (
+                        path.to_owned(),
+                        library.to_owned(),
+                        locations.to_owned(),
+                        vec![library.to_owned()],
+                    )
+                })
+                .collect::<VecDeque<_>>();
+            while let Some((referenced_by, library, locations, chain)) = queue.pop_front() {
+                if library == *path
+                    || deployed_libraries.contains(library.as_str())
+                    || details.contains_key(library.as_str())
+                {
+                    continue;
+                }
+                if let Some(library_references) = contract_references.get(library.as_str()) {
+                    for (dependency, dependency_locations) in library_references.iter() {
+                        let mut dependency_chain = chain.clone();
+                        dependency_chain.push(dependency.to_owned());
+                        queue.push_back((
+                            library.clone(),
+                            dependency.to_owned(),
+                            dependency_locations.to_owned(),
+                            dependency_chain,
+                        ));
+                    }
+                }
+                details.insert(
+                    library,
+                    era_solc::StandardJsonOutputContractMissingLibrary::new(
+                        referenced_by,
+                        locations,
+                        chain,
+                    ),
+                );
+            }
+
+            contract_details.insert(path.to_owned(), details);
+        }
+
+        let contract_libraries = contract_references
+            .into_iter()
+            .map(|(path, references)| {
+                let libraries = references
+                    .into_keys()
+                    .filter(|library| !deployed_libraries.contains(library.as_str()))
+                    .collect();
+                (path, libraries)
+            })
+            .collect();
+
+        Self {
+            contract_libraries,
+            contract_details,
+        }
     }
 
     ///
@@ -39,10 +117,39 @@ impl MissingLibraries {
                 }
             }
         }
+        self.write_details_to_standard_json(standard_json);
 
         if let Some(solc_version) = solc_version {
             standard_json.version = Some(solc_version.default.to_string());
             standard_json.long_version = Some(solc_version.long.to_owned());
         }
     }
+
+    ///
+    /// Writes the missing library details to the standard JSON.
+    ///
+    /// Only the details of contracts that still have missing libraries are written, and only
+    /// for the libraries required through the ones that are still missing.
+    ///
+    pub fn write_details_to_standard_json(self, standard_json: &mut era_solc::StandardJsonOutput) {
+        let mut contract_details = self.contract_details;
+        for (path, file) in standard_json.contracts.iter_mut() {
+            for (name, contract) in file.iter_mut() {
+                if contract.missing_libraries.is_empty() {
+                    continue;
+                }
+
+                let full_name = format!("{path}:{name}");
+                if let Some(mut details) = contract_details.remove(full_name.as_str()) {
+                    details.retain(|_library, detail| {
+                        detail
+                            .chain
+                            .first()
+                            .is_some_and(|library| contract.missing_libraries.contains(library))
+                    });
+                    contract.missing_libraries_details = details;
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,51 @@
+//!
+//! The contract compilation backend.
+//!
+
+use std::str::FromStr;
+
+///
+/// The contract compilation backend.
+///
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Backend {
+    /// Each contract is compiled in a child process of the current executable.
+    /// A crash in LLVM only affects the contract being compiled.
+    #[default]
+    Subprocess,
+    /// Each contract is compiled in a thread of the current process.
+    /// Avoids process startup and IR serialization costs, and does not require
+    /// the current executable to support the `--recursive-process` mode.
+    InProcess,
+}
+
+impl FromStr for Backend {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "subprocess" => Ok(Self::Subprocess),
+            "in-process" => Ok(Self::InProcess),
+            string => anyhow::bail!(
+                "Invalid compilation backend: `{string}`. Available options: {}.",
+                [Self::Subprocess, Self::InProcess]
+                    .into_iter()
+                    .map(|backend| backend.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Backend {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Subprocess => write!(f, "subprocess"),
+            Self::InProcess => write!(f, "in-process"),
+        }
+    }
+}
@@ -36,6 +36,16 @@ pub struct Input {
     pub llvm_options: Vec<String>,
     /// Whether to output EraVM assembly.
     pub output_assembly: bool,
+    /// Whether to output the EraVM source map.
+    pub output_source_map: bool,
+    /// Whether to output the unoptimized LLVM IR.
+    pub output_llvm_ir: bool,
+    /// Whether to output the optimized LLVM IR.
+    pub output_llvm_ir_optimized: bool,
+    /// Whether to output the Yul IR passed to the code generator.
+    pub output_yul_optimized: bool,
+    /// Whether to output the compilation statistics.
+    pub output_statistics: bool,
     /// The debug output config.
     pub debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 }
@@ -56,6 +66,11 @@ impl Input {
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
         output_assembly: bool,
+        output_source_map: bool,
+        output_llvm_ir: bool,
+        output_llvm_ir_optimized: bool,
+        output_yul_optimized: bool,
+        output_statistics: bool,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> Self {
         Self {
@@ -70,6 +85,11 @@ impl Input {
             optimizer_settings,
             llvm_options,
             output_assembly,
+            output_source_map,
+            output_llvm_ir,
+            output_llvm_ir_optimized,
+            output_yul_optimized,
+            output_statistics,
             debug_config,
         }
     }
@@ -2,6 +2,7 @@
 //! Process for compiling a single compilation unit.
 //!
 
+pub mod backend;
 pub mod input_eravm;
 pub mod output_eravm;
 
@@ -11,6 +12,7 @@ use std::process::Command;
 use std::sync::OnceLock;
 use std::thread::Builder;
 
+use self::backend::Backend;
 use self::input_eravm::Input as EraVMInput;
 use self::output_eravm::Output as EraVMOutput;
 
@@ -26,35 +28,18 @@ pub fn run() -> anyhow::Result<()> {
     let input: EraVMInput = era_compiler_common::deserialize_from_str(input_json.as_str())
         .map_err(|error| anyhow::anyhow!("Stdin parsing error: {error}"))?;
 
-    let source_location =
-        era_solc::StandardJsonOutputErrorSourceLocation::new(input.contract.name.path.clone());
-
     let result = Builder::new()
         .stack_size(crate::WORKER_THREAD_STACK_SIZE)
-        .spawn(move || {
-            input
-                .contract
-                .compile_to_eravm(
-                    input.solc_version,
-                    input.identifier_paths,
-                    input.missing_libraries,
-                    input.factory_dependencies,
-                    input.enable_eravm_extensions,
-                    input.metadata_hash_type,
-                    input.append_cbor,
-                    input.optimizer_settings,
-                    input.llvm_options,
-                    input.output_assembly,
-                    input.debug_config,
-                )
-                .map(EraVMOutput::new)
-                .map_err(|error| {
-                    era_solc::StandardJsonOutputError::new_error(error, Some(source_location), None)
-                })
-        })
+        .spawn(move || compile(input))
         .expect("Threading error")
         .join()
-        .expect("Threading error");
+        .expect("Threading error")
+        .map(|mut output| {
+            if let Some(statistics) = output.build.statistics.as_mut() {
+                statistics.peak_memory_bytes = peak_memory();
+            }
+            output
+        });
 
     serde_json::to_writer(std::io::stdout(), &result)
         .map_err(|error| anyhow::anyhow!("Stdout writing error: {error}"))?;
@@ -63,6 +48,52 @@ pub fn run() -> anyhow::Result<()> {
     Ok(())
 }
 
+///
+/// Compiles a single contract with the specified `backend`.
+///
+/// The in-process backend must be called from a thread with at least
+/// `WORKER_THREAD_STACK_SIZE` bytes of stack.
+///
+pub fn call_eravm(backend: Backend, path: &str, input: EraVMInput) -> crate::Result<EraVMOutput> {
+    match backend {
+        Backend::Subprocess => call(path, input),
+        Backend::InProcess => compile(input),
+    }
+}
+
+///
+/// Compiles a single contract in the current thread.
+///
+fn compile(input: EraVMInput) -> crate::Result<EraVMOutput> {
+    let source_location =
+        era_solc::StandardJsonOutputErrorSourceLocation::new(input.contract.name.path.clone());
+
+    input
+        .contract
+        .compile_to_eravm(
+            input.solc_version,
+            input.identifier_paths,
+            input.missing_libraries,
+            input.factory_dependencies,
+            input.enable_eravm_extensions,
+            input.metadata_hash_type,
+            input.append_cbor,
+            input.optimizer_settings,
+            input.llvm_options,
+            input.output_assembly,
+            input.output_source_map,
+            input.output_llvm_ir,
+            input.output_llvm_ir_optimized,
+            input.output_yul_optimized,
+            input.output_statistics,
+            input.debug_config,
+        )
+        .map(EraVMOutput::new)
+        .map_err(|error| {
+            era_solc::StandardJsonOutputError::new_error(error, Some(source_location), None)
+        })
+}
+
 ///
 /// Runs this process recursively to compile a single contract.
 ///
@@ -127,3 +158,21 @@ where
         }
     }
 }
+
+///
+/// Returns the peak resident memory of the current process in bytes.
+///
+/// Only supported on Linux, where it is read from `/proc/self/status`.
+///
+fn peak_memory() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
@@ -2,9 +2,10 @@
 //! The contract EVM legacy assembly source code.
 //!
 
-use std::collections::BTreeSet;
+use std::collections::BTreeMap;
 
 use crate::evmla::assembly::Assembly;
+use crate::source_map::SourceMap;
 
 ///
 /// The contract EVM legacy assembly source code.
@@ -32,12 +33,32 @@ impl EVMLA {
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their references.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(
+        &self,
+    ) -> BTreeMap<String, Vec<era_solc::StandardJsonOutputContractMissingLibraryLocation>> {
         self.assembly.get_missing_libraries()
     }
 
+    ///
+    /// Get the source map of the deploy and runtime code.
+    ///
+    pub fn get_source_map(&self) -> anyhow::Result<SourceMap> {
+        let deploy_code = self
+            .assembly
+            .code
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Deploy code instructions not found"))?;
+        let runtime_code = self
+            .assembly
+            .runtime_code()?
+            .code
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Runtime code instructions not found"))?;
+        Ok(SourceMap::from_evmla(deploy_code, runtime_code))
+    }
+
     ///
     /// Get the list of EVM dependencies.
     ///
@@ -7,8 +7,11 @@ pub mod evmla;
 pub mod llvm_ir;
 pub mod yul;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use crate::source_map::SourceMap;
+
 use self::eravm_assembly::EraVMAssembly;
 use self::evmla::EVMLA;
 use self::llvm_ir::LLVMIR;
@@ -43,14 +46,40 @@ impl IR {
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the list of factory dependencies without draining it.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_factory_dependencies(&self) -> BTreeSet<String> {
+        match self {
+            IR::Yul(ref yul) => yul.object.0.factory_dependencies.iter().cloned().collect(),
+            IR::EVMLA(ref evm) => evm.assembly.factory_dependencies.iter().cloned().collect(),
+            IR::LLVMIR(_) => BTreeSet::new(),
+            IR::EraVMAssembly(_) => BTreeSet::new(),
+        }
+    }
+
+    ///
+    /// Get the missing deployable libraries with locations of all their references.
+    ///
+    pub fn get_missing_libraries(
+        &self,
+    ) -> BTreeMap<String, Vec<era_solc::StandardJsonOutputContractMissingLibraryLocation>> {
         match self {
             Self::Yul(inner) => inner.get_missing_libraries(),
             Self::EVMLA(inner) => inner.get_missing_libraries(),
-            Self::LLVMIR(_inner) => BTreeSet::new(),
-            Self::EraVMAssembly(_inner) => BTreeSet::new(),
+            Self::LLVMIR(_inner) => BTreeMap::new(),
+            Self::EraVMAssembly(_inner) => BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Get the source map, if the IR carries source code locations.
+    ///
+    pub fn get_source_map(&self) -> anyhow::Result<Option<SourceMap>> {
+        match self {
+            Self::Yul(inner) => Ok(Some(inner.source_map.clone())),
+            Self::EVMLA(inner) => inner.get_source_map().map(Some),
+            Self::LLVMIR(_inner) => Ok(None),
+            Self::EraVMAssembly(_inner) => Ok(None),
         }
     }
 }
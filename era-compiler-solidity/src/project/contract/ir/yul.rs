@@ -2,11 +2,14 @@
 //! The contract Yul source code.
 //!
 
-use std::collections::BTreeSet;
+use std::collections::BTreeMap;
 
+use era_yul::util::printer::write_printer::WritePrinter;
 use era_yul::yul::lexer::Lexer;
 use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::visitor::Visitor;
 
+use crate::source_map::SourceMap;
 use crate::yul::parser::dialect::era::EraDialect;
 use crate::yul::parser::wrapper::Wrap;
 
@@ -17,6 +20,9 @@ use crate::yul::parser::wrapper::Wrap;
 pub struct Yul {
     /// The Yul AST object.
     pub object: crate::yul::parser::statement::object::Object,
+    /// The source map built from the `solc` source location annotations.
+    #[serde(default)]
+    pub source_map: SourceMap,
 }
 
 impl Yul {
@@ -40,8 +46,11 @@ impl Yul {
         let object = Object::parse(&mut lexer, None)
             .map_err(|error| anyhow::anyhow!("Yul parsing: {error:?}"))?;
 
+        let source_map = SourceMap::from_yul_comments(lexer.comments());
+
         Ok(Some(Self {
             object: object.wrap(),
+            source_map,
         }))
     }
 
@@ -53,10 +62,24 @@ impl Yul {
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their references.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
-        self.object.0.get_missing_libraries()
+    pub fn get_missing_libraries(
+        &self,
+    ) -> BTreeMap<String, Vec<era_solc::StandardJsonOutputContractMissingLibraryLocation>> {
+        let mut missing_libraries = BTreeMap::new();
+        for (library, location) in self.object.0.get_missing_libraries().into_iter() {
+            missing_libraries
+                .entry(library)
+                .or_insert_with(Vec::new)
+                .push(
+                    era_solc::StandardJsonOutputContractMissingLibraryLocation::Yul {
+                        line: location.line,
+                        column: location.column,
+                    },
+                );
+        }
+        missing_libraries
     }
 
     ///
@@ -68,6 +91,17 @@ impl Yul {
     ) -> era_yul::Dependencies {
         self.object.0.get_evm_dependencies(runtime_code)
     }
+
+    ///
+    /// Prints the Yul object as it is passed to the EraVM code generator.
+    ///
+    /// The `solc` comments, including the source location annotations, are not preserved.
+    ///
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        WritePrinter::new(&mut text).visit_object(&self.object.0);
+        text
+    }
 }
 
 impl era_compiler_llvm_context::EraVMWriteLLVM for Yul {
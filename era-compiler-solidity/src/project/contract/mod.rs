@@ -7,10 +7,14 @@ pub mod metadata;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::time::Duration;
+use std::time::Instant;
 
 use era_compiler_llvm_context::IContext;
 
+use crate::build_eravm::assembly_statistics::AssemblyStatistics;
 use crate::build_eravm::contract::Contract as EraVMContractBuild;
+use crate::source_map::SourceMap;
 
 use self::ir::IR;
 use self::metadata::Metadata;
@@ -63,6 +67,10 @@ impl Contract {
     ///
     /// Compiles the specified contract to EraVM, returning its build artifacts.
     ///
+    /// If the size fallback is enabled, the LLVM context recompiles the contract with `-Oz` when
+    /// it exceeds the bytecode size limit, which is recorded in the build. The metadata keeps
+    /// the original settings.
+    ///
     pub fn compile_to_eravm(
         self,
         solc_version: Option<era_solc::Version>,
@@ -75,12 +83,17 @@ impl Contract {
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
         output_assembly: bool,
+        output_source_map: bool,
+        output_llvm_ir: bool,
+        output_llvm_ir_optimized: bool,
+        output_yul_optimized: bool,
+        output_statistics: bool,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<EraVMContractBuild> {
         use era_compiler_llvm_context::EraVMWriteLLVM;
 
         let llvm = inkwell::context::Context::create();
-        let optimizer = era_compiler_llvm_context::Optimizer::new(optimizer_settings);
+        let optimizer = era_compiler_llvm_context::Optimizer::new(optimizer_settings.clone());
 
         let metadata = Metadata::new(
             self.source_metadata,
@@ -128,14 +141,28 @@ impl Contract {
             None
         };
 
-        let build = match self.ir {
+        let source_map = if output_source_map {
+            self.ir.get_source_map()?
+        } else {
+            None
+        };
+        let is_debug_location_required = source_map.is_some();
+        let yul_optimized = match self.ir {
+            IR::Yul(ref yul) if output_yul_optimized => Some(yul.to_text()),
+            _ => None,
+        };
+
+        let is_assembly_required = output_assembly || output_source_map || output_statistics;
+        let mut ir_generation_time = Duration::ZERO;
+        let mut llvm_time = Duration::ZERO;
+        let (mut build, llvm_ir) = match self.ir {
             IR::Yul(mut yul) => {
                 let module = llvm.create_module(self.name.full_path.as_str());
                 let mut context: era_compiler_llvm_context::EraVMContext =
                     era_compiler_llvm_context::EraVMContext::new(
                         &llvm,
                         module,
-                        llvm_options,
+                        llvm_options.clone(),
                         optimizer,
                         debug_config,
                     );
@@ -148,17 +175,26 @@ impl Contract {
                 );
                 context.set_yul_data(yul_data);
 
+                let ir_generation_start = Instant::now();
                 yul.declare(&mut context)?;
-                yul.into_llvm(&mut context)
-                    .map_err(|error| anyhow::anyhow!("LLVM IR generator: {error}"))?;
+                SourceMap::with_debug_locations(is_debug_location_required, || {
+                    yul.into_llvm(&mut context)
+                })
+                .map_err(|error| anyhow::anyhow!("LLVM IR generator: {error}"))?;
+                ir_generation_time = ir_generation_start.elapsed();
 
-                context.build(
+                let llvm_ir =
+                    output_llvm_ir.then(|| context.module().print_to_string().to_string());
+                let llvm_start = Instant::now();
+                let build = context.build(
                     self.name.full_path.as_str(),
                     metadata_bytes,
                     cbor_data,
-                    output_assembly,
+                    is_assembly_required,
                     false,
-                )?
+                )?;
+                llvm_time = llvm_start.elapsed();
+                (build, llvm_ir)
             }
             IR::EVMLA(mut evmla) => {
                 let solc_version = solc_version
@@ -170,7 +206,7 @@ impl Contract {
                     era_compiler_llvm_context::EraVMContext::new(
                         &llvm,
                         module,
-                        llvm_options,
+                        llvm_options.clone(),
                         optimizer,
                         debug_config,
                     );
@@ -181,18 +217,26 @@ impl Contract {
                     era_compiler_llvm_context::EraVMContextEVMLAData::new(solc_version.default);
                 context.set_evmla_data(evmla_data);
 
+                let ir_generation_start = Instant::now();
                 evmla.declare(&mut context)?;
-                evmla
-                    .into_llvm(&mut context)
-                    .map_err(|error| anyhow::anyhow!("LLVM IR generator: {error}"))?;
+                SourceMap::with_debug_locations(is_debug_location_required, || {
+                    evmla.into_llvm(&mut context)
+                })
+                .map_err(|error| anyhow::anyhow!("LLVM IR generator: {error}"))?;
+                ir_generation_time = ir_generation_start.elapsed();
 
-                context.build(
+                let llvm_ir =
+                    output_llvm_ir.then(|| context.module().print_to_string().to_string());
+                let llvm_start = Instant::now();
+                let build = context.build(
                     self.name.full_path.as_str(),
                     metadata_bytes,
                     cbor_data,
-                    output_assembly,
+                    is_assembly_required,
                     false,
-                )?
+                )?;
+                llvm_time = llvm_start.elapsed();
+                (build, llvm_ir)
             }
             IR::LLVMIR(mut llvm_ir) => {
                 llvm_ir.source.push(char::from(0));
@@ -209,20 +253,26 @@ impl Contract {
                     era_compiler_llvm_context::EraVMContext::new(
                         &llvm,
                         module,
-                        llvm_options,
+                        llvm_options.clone(),
                         optimizer,
                         debug_config,
                     );
 
-                context.build(
+                let llvm_ir =
+                    output_llvm_ir.then(|| context.module().print_to_string().to_string());
+                let llvm_start = Instant::now();
+                let build = context.build(
                     self.name.full_path.as_str(),
                     metadata_bytes,
                     cbor_data,
-                    output_assembly,
+                    is_assembly_required,
                     false,
-                )?
+                )?;
+                llvm_time = llvm_start.elapsed();
+                (build, llvm_ir)
             }
             IR::EraVMAssembly(eravm_assembly) => {
+                let llvm_start = Instant::now();
                 let target_machine = era_compiler_llvm_context::TargetMachine::new(
                     era_compiler_common::Target::EraVM,
                     optimizer.settings(),
@@ -234,38 +284,81 @@ impl Contract {
                     eravm_assembly.source.as_str(),
                     debug_config.as_ref(),
                 )?;
-                let assembly_text = if output_assembly {
+                let assembly_text = if output_assembly || output_statistics {
                     Some(eravm_assembly.source)
                 } else {
                     None
                 };
-                era_compiler_llvm_context::eravm_build(
+                let build = era_compiler_llvm_context::eravm_build(
                     bytecode_buffer,
                     metadata_bytes,
                     cbor_data,
                     assembly_text,
-                )?
+                )?;
+                llvm_time = llvm_start.elapsed();
+                (build, None)
             }
         };
 
-        Ok(EraVMContractBuild::new(
+        // the build returns the module it has compiled, which is the size-optimized one on the size fallback
+        let llvm_ir_optimized = build.llvm_ir_optimized.take();
+        let llvm_ir_optimized = if output_llvm_ir_optimized {
+            llvm_ir_optimized
+        } else {
+            None
+        };
+        let source_map = source_map
+            .zip(build.assembly.as_deref())
+            .map(|(source_map, assembly)| source_map.encode(assembly));
+        let statistics = if output_statistics {
+            let assembly_statistics = build
+                .assembly
+                .as_deref()
+                .map(AssemblyStatistics::from_assembly)
+                .unwrap_or_default();
+            Some(era_solc::StandardJsonOutputContractEraVMStatistics::new(
+                ir_generation_time.as_millis() as u64,
+                llvm_time.as_millis() as u64,
+                assembly_statistics.functions,
+                assembly_statistics.instructions,
+                build.is_size_fallback,
+            ))
+        } else {
+            None
+        };
+        if !output_assembly {
+            build.assembly = None;
+        }
+
+        let is_size_fallback = build.is_size_fallback;
+        let mut build = EraVMContractBuild::new(
             self.name,
             build,
             metadata_json,
             missing_libraries,
             factory_dependencies,
             era_compiler_common::ObjectFormat::ELF,
-        ))
+        );
+        build.is_size_fallback = is_size_fallback;
+        build.source_map = source_map;
+        build.llvm_ir = llvm_ir;
+        build.llvm_ir_optimized = llvm_ir_optimized;
+        build.yul_optimized = yul_optimized;
+        build.statistics = statistics;
+        Ok(build)
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their references.
     ///
-    pub fn get_missing_libraries(&self, deployed_libraries: &BTreeSet<String>) -> BTreeSet<String> {
+    pub fn get_missing_libraries(
+        &self,
+        deployed_libraries: &BTreeSet<String>,
+    ) -> BTreeMap<String, Vec<era_solc::StandardJsonOutputContractMissingLibraryLocation>> {
         self.ir
             .get_missing_libraries()
             .into_iter()
-            .filter(|library| !deployed_libraries.contains(library))
-            .collect::<BTreeSet<String>>()
+            .filter(|(library, _location)| !deployed_libraries.contains(library))
+            .collect()
     }
 }
@@ -7,14 +7,17 @@ pub mod contract;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::path::PathBuf;
+use std::time::Instant;
 
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
 use crate::build_eravm::contract::Contract as EraVMContractBuild;
 use crate::build_eravm::Build as EraVMBuild;
+use crate::cache::Cache;
 use crate::evmla::assembly::Assembly;
 use crate::missing_libraries::MissingLibraries;
+use crate::process::backend::Backend as ProcessBackend;
 use crate::process::input_eravm::Input as EraVMProcessInput;
 use crate::process::output_eravm::Output as EraVMOutput;
 
@@ -208,6 +211,30 @@ impl Project {
                 },
             }
         }
+
+        let mut identifiers: BTreeMap<String, String> = BTreeMap::new();
+        let mut duplicates = Vec::new();
+        for contract in contracts.values() {
+            let path = contract.name.path.as_str();
+            if let Some(previous) =
+                identifiers.insert(contract.identifier().to_owned(), path.to_owned())
+            {
+                duplicates.push((
+                    path.to_owned(),
+                    anyhow::anyhow!(
+                        "Yul object `{}` is declared in both `{previous}` and `{path}`.",
+                        contract.identifier(),
+                    ),
+                ));
+            }
+        }
+        for (path, error) in duplicates.into_iter() {
+            match solc_output {
+                Some(ref mut solc_output) => solc_output.push_error(Some(path), error),
+                None => anyhow::bail!(error),
+            }
+        }
+
         Ok(Self::new(
             era_solc::StandardJsonInputLanguage::Yul,
             solc_version.cloned(),
@@ -352,9 +379,44 @@ impl Project {
         ))
     }
 
+    ///
+    /// Removes all contracts except the `selected` ones and the transitive closure
+    /// of their factory dependencies.
+    ///
+    pub fn retain_selected_contracts(&mut self, selected: &BTreeSet<String>) -> anyhow::Result<()> {
+        let mut queue = Vec::with_capacity(selected.len());
+        for path in selected.iter() {
+            if !self.contracts.contains_key(path.as_str()) {
+                anyhow::bail!("Contract `{path}` is not found in the project.");
+            }
+            queue.push(path.to_owned());
+        }
+
+        let mut retained = BTreeSet::new();
+        while let Some(path) = queue.pop() {
+            if !retained.insert(path.clone()) {
+                continue;
+            }
+            let contract = self.contracts.get(path.as_str()).expect("Always exists");
+            queue.extend(
+                contract
+                    .ir
+                    .get_factory_dependencies()
+                    .iter()
+                    .filter_map(|identifier| self.identifier_paths.get(identifier).cloned()),
+            );
+        }
+
+        self.contracts.retain(|path, _| retained.contains(path));
+        Ok(())
+    }
+
     ///
     /// Compiles all contracts to EraVM, returning their build artifacts.
     ///
+    /// If `cache` is provided, unchanged contracts are taken from it instead of being compiled.
+    /// The cache is bypassed if `debug_config` is set, as the IR dumps are produced during compilation.
+    ///
     pub fn compile_to_eravm(
         self,
         messages: &mut Vec<era_solc::StandardJsonOutputError>,
@@ -364,37 +426,118 @@ impl Project {
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
         output_assembly: bool,
+        output_source_map: bool,
+        output_llvm_ir: bool,
+        output_llvm_ir_optimized: bool,
+        output_yul_optimized: bool,
+        output_statistics: bool,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+        backend: ProcessBackend,
+        cache: Option<&Cache>,
     ) -> anyhow::Result<EraVMBuild> {
         let deployed_libraries = self.libraries.as_paths();
-        let results = self.contracts.into_par_iter().map(|(path, mut contract)| {
-            let factory_dependencies = contract.ir
-                .drain_factory_dependencies()
-                .iter()
-                .map(|identifier| {
-                    self.identifier_paths.get(identifier).cloned().expect("Always exists")
-                })
-                .collect();
-            let missing_libraries = contract.get_missing_libraries(&deployed_libraries);
-            let input = EraVMProcessInput::new(
-                contract,
-                self.solc_version.clone(),
-                self.identifier_paths.clone(),
-                missing_libraries,
-                factory_dependencies,
-                enable_eravm_extensions,
-                metadata_hash_type,
-                append_cbor,
-                optimizer_settings.clone(),
-                llvm_options.clone(),
-                output_assembly,
-                debug_config.clone(),
-            );
-            let result: crate::Result<EraVMOutput> =
-                crate::process::call(path.as_str(), input);
-            let result = result.map(|output| output.build);
-            (path, result)
-        }).collect::<BTreeMap<String, Result<EraVMContractBuild, era_solc::StandardJsonOutputError>>>();
+        let compile = || {
+            self.contracts.into_par_iter().map(|(path, mut contract)| {
+                let factory_dependency_identifiers = contract.ir.drain_factory_dependencies();
+                let factory_dependencies = match factory_dependency_identifiers
+                    .iter()
+                    .map(|identifier| {
+                        self.identifier_paths.get(identifier).cloned().ok_or_else(|| {
+                            era_solc::StandardJsonOutputError::new_error(
+                                format!("Factory dependency `{identifier}` is not found among the project contracts."),
+                                Some(era_solc::StandardJsonOutputErrorSourceLocation::new(contract.name.path.clone())),
+                                None,
+                            )
+                        })
+                    })
+                    .collect::<Result<BTreeSet<String>, era_solc::StandardJsonOutputError>>() {
+                    Ok(factory_dependencies) => factory_dependencies,
+                    Err(error) => return (path, Err(error), None),
+                };
+                let missing_libraries = contract
+                    .get_missing_libraries(&deployed_libraries)
+                    .into_keys()
+                    .collect();
+                let input = EraVMProcessInput::new(
+                    contract,
+                    self.solc_version.clone(),
+                    self.identifier_paths.clone(),
+                    missing_libraries,
+                    factory_dependencies,
+                    enable_eravm_extensions,
+                    metadata_hash_type,
+                    append_cbor,
+                    optimizer_settings.clone(),
+                    llvm_options.clone(),
+                    output_assembly,
+                    output_source_map,
+                    output_llvm_ir,
+                    output_llvm_ir_optimized,
+                    output_yul_optimized,
+                    output_statistics,
+                    debug_config.clone(),
+                );
+                let cache_key = match cache {
+                    Some(cache) if input.debug_config.is_none() => {
+                        let cache_key = Cache::key(&input, &factory_dependency_identifiers);
+                        if let Some(mut build) = cache.get(cache_key.as_str()) {
+                            if let Some(statistics) = build.statistics.as_mut() {
+                                statistics.cached = true;
+                            }
+                            return (path, Ok(build), None);
+                        }
+                        Some((cache, cache_key))
+                    }
+                    _ => None,
+                };
+                let start = Instant::now();
+                let result: crate::Result<EraVMOutput> =
+                    crate::process::call_eravm(backend, path.as_str(), input);
+                let wall_time = start.elapsed();
+                let result = result.map(|mut output| {
+                    if let Some(statistics) = output.build.statistics.as_mut() {
+                        statistics.wall_time_ms = wall_time.as_millis() as u64;
+                    }
+                    output.build
+                });
+                let warning = match (cache_key, result.as_ref()) {
+                    // the cache is best-effort, so a failure to store an entry does not fail compilation
+                    (Some((cache, cache_key)), Ok(build)) => cache
+                        .put(cache_key.as_str(), build)
+                        .err()
+                        .map(|error| {
+                            era_solc::StandardJsonOutputError::new_warning(
+                                format!("Contract `{path}` build caching: {error}"),
+                                None,
+                                None,
+                            )
+                        }),
+                    _ => None,
+                };
+                (path, result, warning)
+            }).collect::<Vec<(String, Result<EraVMContractBuild, era_solc::StandardJsonOutputError>, Option<era_solc::StandardJsonOutputError>)>>()
+        };
+        let results = match backend {
+            ProcessBackend::Subprocess => compile(),
+            ProcessBackend::InProcess => {
+                // the global pool may be configured by the library user, so LLVM gets its own large-stack pool
+                let thread_pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(rayon::current_num_threads())
+                    .stack_size(crate::WORKER_THREAD_STACK_SIZE)
+                    .build()
+                    .map_err(|error| {
+                        anyhow::anyhow!("Thread pool configuration failure: {error}")
+                    })?;
+                thread_pool.install(compile)
+            }
+        };
+        let results = results
+            .into_iter()
+            .map(|(path, result, warning)| {
+                messages.extend(warning);
+                (path, result)
+            })
+            .collect();
         Ok(EraVMBuild::new(results, messages))
     }
 
@@ -412,6 +555,6 @@ impl Project {
                 )
             })
             .collect();
-        MissingLibraries::new(missing_libraries)
+        MissingLibraries::new(missing_libraries, deployed_libraries)
     }
 }
@@ -0,0 +1,312 @@
+//!
+//! The compilation session builder.
+//!
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use crate::cache::Cache;
+use crate::process::backend::Backend as ProcessBackend;
+
+use super::Session;
+
+///
+/// The compilation session builder.
+///
+/// The defaults match the CLI defaults, except for the backend, which is in-process, as
+/// the embedding executable does not support the recursive process mode.
+///
+#[derive(Debug, Clone)]
+pub struct Builder {
+    /// The `solc` executable path.
+    solc: Option<String>,
+    /// The `solc` codegen.
+    codegen: Option<era_solc::StandardJsonInputCodegen>,
+    /// The EVM version passed to `solc`.
+    evm_version: Option<era_compiler_common::EVMVersion>,
+    /// Whether to enable EraVM extensions.
+    enable_eravm_extensions: bool,
+    /// The metadata hash type.
+    metadata_hash_type: era_compiler_common::MetadataHashType,
+    /// Whether to append the CBOR metadata.
+    append_cbor: bool,
+    /// Whether to use literal content in metadata.
+    metadata_literal: bool,
+    /// The `solc` base path.
+    base_path: Option<String>,
+    /// The `solc` include paths.
+    include_paths: Vec<String>,
+    /// The `solc` allow paths.
+    allow_paths: Option<String>,
+    /// The `solc` import remappings.
+    remappings: BTreeSet<String>,
+    /// The deployed libraries in the `<file>:<contract>=<address>` format.
+    libraries: Vec<String>,
+    /// The LLVM optimizer settings.
+    optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    /// The extra LLVM options.
+    llvm_options: Vec<String>,
+    /// Whether to output EraVM assembly.
+    output_assembly: bool,
+    /// The suppressed errors.
+    suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
+    /// The suppressed warnings.
+    suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
+    /// The debug output config.
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    /// The contract compilation backend.
+    backend: ProcessBackend,
+    /// The persistent build cache directory.
+    cache_dir: Option<PathBuf>,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builder {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self {
+            solc: None,
+            codegen: None,
+            evm_version: None,
+            enable_eravm_extensions: false,
+            metadata_hash_type: era_compiler_common::MetadataHashType::IPFS,
+            append_cbor: true,
+            metadata_literal: false,
+            base_path: None,
+            include_paths: vec![],
+            allow_paths: None,
+            remappings: BTreeSet::new(),
+            libraries: vec![],
+            optimizer_settings: era_compiler_llvm_context::OptimizerSettings::cycles(),
+            llvm_options: vec![],
+            output_assembly: false,
+            suppressed_errors: vec![],
+            suppressed_warnings: vec![],
+            debug_config: None,
+            backend: ProcessBackend::InProcess,
+            cache_dir: None,
+        }
+    }
+
+    ///
+    /// Sets the `solc` executable path.
+    ///
+    pub fn solc(mut self, path: impl Into<String>) -> Self {
+        self.solc = Some(path.into());
+        self
+    }
+
+    ///
+    /// Sets the `solc` codegen.
+    ///
+    pub fn codegen(mut self, codegen: era_solc::StandardJsonInputCodegen) -> Self {
+        self.codegen = Some(codegen);
+        self
+    }
+
+    ///
+    /// Sets the EVM version passed to `solc`.
+    ///
+    pub fn evm_version(mut self, evm_version: era_compiler_common::EVMVersion) -> Self {
+        self.evm_version = Some(evm_version);
+        self
+    }
+
+    ///
+    /// Enables EraVM extensions.
+    ///
+    pub fn enable_eravm_extensions(mut self, enable_eravm_extensions: bool) -> Self {
+        self.enable_eravm_extensions = enable_eravm_extensions;
+        self
+    }
+
+    ///
+    /// Sets the metadata hash type.
+    ///
+    pub fn metadata_hash_type(
+        mut self,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+    ) -> Self {
+        self.metadata_hash_type = metadata_hash_type;
+        self
+    }
+
+    ///
+    /// Sets whether to append the CBOR metadata.
+    ///
+    pub fn append_cbor(mut self, append_cbor: bool) -> Self {
+        self.append_cbor = append_cbor;
+        self
+    }
+
+    ///
+    /// Sets whether to use literal content in metadata.
+    ///
+    pub fn metadata_literal(mut self, metadata_literal: bool) -> Self {
+        self.metadata_literal = metadata_literal;
+        self
+    }
+
+    ///
+    /// Sets the `solc` base path.
+    ///
+    pub fn base_path(mut self, base_path: impl Into<String>) -> Self {
+        self.base_path = Some(base_path.into());
+        self
+    }
+
+    ///
+    /// Sets the `solc` include paths.
+    ///
+    pub fn include_paths(mut self, include_paths: Vec<String>) -> Self {
+        self.include_paths = include_paths;
+        self
+    }
+
+    ///
+    /// Sets the `solc` allow paths.
+    ///
+    pub fn allow_paths(mut self, allow_paths: impl Into<String>) -> Self {
+        self.allow_paths = Some(allow_paths.into());
+        self
+    }
+
+    ///
+    /// Sets the `solc` import remappings.
+    ///
+    pub fn remappings(mut self, remappings: BTreeSet<String>) -> Self {
+        self.remappings = remappings;
+        self
+    }
+
+    ///
+    /// Sets the deployed libraries in the `<file>:<contract>=<address>` format.
+    ///
+    pub fn libraries(mut self, libraries: Vec<String>) -> Self {
+        self.libraries = libraries;
+        self
+    }
+
+    ///
+    /// Sets the LLVM optimizer settings.
+    ///
+    pub fn optimizer_settings(
+        mut self,
+        optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    ) -> Self {
+        self.optimizer_settings = optimizer_settings;
+        self
+    }
+
+    ///
+    /// Sets the extra LLVM options.
+    ///
+    pub fn llvm_options(mut self, llvm_options: Vec<String>) -> Self {
+        self.llvm_options = llvm_options;
+        self
+    }
+
+    ///
+    /// Sets whether to output EraVM assembly.
+    ///
+    pub fn output_assembly(mut self, output_assembly: bool) -> Self {
+        self.output_assembly = output_assembly;
+        self
+    }
+
+    ///
+    /// Sets the suppressed errors.
+    ///
+    pub fn suppressed_errors(
+        mut self,
+        suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
+    ) -> Self {
+        self.suppressed_errors = suppressed_errors;
+        self
+    }
+
+    ///
+    /// Sets the suppressed warnings.
+    ///
+    pub fn suppressed_warnings(
+        mut self,
+        suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
+    ) -> Self {
+        self.suppressed_warnings = suppressed_warnings;
+        self
+    }
+
+    ///
+    /// Sets the debug output config.
+    ///
+    pub fn debug_config(mut self, debug_config: era_compiler_llvm_context::DebugConfig) -> Self {
+        self.debug_config = Some(debug_config);
+        self
+    }
+
+    ///
+    /// Sets the contract compilation backend.
+    ///
+    /// The subprocess backend requires `EXECUTABLE` to point to a `zksolc` executable.
+    ///
+    pub fn backend(mut self, backend: ProcessBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    ///
+    /// Sets the persistent build cache directory.
+    ///
+    pub fn cache_dir(mut self, cache_dir: PathBuf) -> Self {
+        self.cache_dir = Some(cache_dir);
+        self
+    }
+
+    ///
+    /// Validates the settings and creates the session.
+    ///
+    pub fn build(self) -> anyhow::Result<Session> {
+        era_compiler_llvm_context::initialize_target();
+
+        let solc_compiler = match self.solc {
+            Some(ref executable) => Some(era_solc::Compiler::try_from_path(executable.as_str())?),
+            None => None,
+        };
+        era_compiler_common::Libraries::try_from(self.libraries.as_slice())?;
+        let cache = match self.cache_dir {
+            Some(cache_dir) => Some(Cache::try_new(cache_dir)?),
+            None => None,
+        };
+
+        Ok(Session {
+            solc_compiler,
+            codegen: self.codegen,
+            evm_version: self.evm_version,
+            enable_eravm_extensions: self.enable_eravm_extensions,
+            metadata_hash_type: self.metadata_hash_type,
+            append_cbor: self.append_cbor,
+            metadata_literal: self.metadata_literal,
+            base_path: self.base_path,
+            include_paths: self.include_paths,
+            allow_paths: self.allow_paths,
+            remappings: self.remappings,
+            libraries: self.libraries,
+            optimizer_settings: self.optimizer_settings,
+            llvm_options: self.llvm_options,
+            output_assembly: self.output_assembly,
+            suppressed_errors: self.suppressed_errors,
+            suppressed_warnings: self.suppressed_warnings,
+            debug_config: self.debug_config,
+            backend: self.backend,
+            cache,
+            warnings: vec![],
+        })
+    }
+}
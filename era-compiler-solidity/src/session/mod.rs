@@ -0,0 +1,340 @@
+//!
+//! The compilation session.
+//!
+
+pub mod builder;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use era_solc::CollectableError;
+
+use crate::build_eravm::Build as EraVMBuild;
+use crate::cache::Cache;
+use crate::process::backend::Backend as ProcessBackend;
+use crate::project::Project;
+
+///
+/// The compilation session.
+///
+/// Holds the validated compilation settings and can be used to compile any number of projects.
+/// Unlike the CLI mode functions, the session never writes to stdout or exits the process:
+/// errors are returned, and warnings are collected until taken with `take_warnings`.
+///
+#[derive(Debug, Clone)]
+pub struct Session {
+    /// The `solc` compiler, or `None` to use the default one from `${PATH}` when required.
+    pub solc_compiler: Option<era_solc::Compiler>,
+    /// The `solc` codegen.
+    pub codegen: Option<era_solc::StandardJsonInputCodegen>,
+    /// The EVM version passed to `solc`.
+    pub evm_version: Option<era_compiler_common::EVMVersion>,
+    /// Whether to enable EraVM extensions.
+    pub enable_eravm_extensions: bool,
+    /// The metadata hash type.
+    pub metadata_hash_type: era_compiler_common::MetadataHashType,
+    /// Whether to append the CBOR metadata.
+    pub append_cbor: bool,
+    /// Whether to use literal content in metadata.
+    pub metadata_literal: bool,
+    /// The `solc` base path.
+    pub base_path: Option<String>,
+    /// The `solc` include paths.
+    pub include_paths: Vec<String>,
+    /// The `solc` allow paths.
+    pub allow_paths: Option<String>,
+    /// The `solc` import remappings.
+    pub remappings: BTreeSet<String>,
+    /// The deployed libraries in the `<file>:<contract>=<address>` format.
+    pub libraries: Vec<String>,
+    /// The LLVM optimizer settings.
+    pub optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    /// The extra LLVM options.
+    pub llvm_options: Vec<String>,
+    /// Whether to output EraVM assembly.
+    pub output_assembly: bool,
+    /// The suppressed errors.
+    pub suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
+    /// The suppressed warnings.
+    pub suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
+    /// The debug output config.
+    pub debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    /// The contract compilation backend.
+    pub backend: ProcessBackend,
+    /// The persistent build cache.
+    pub cache: Option<Cache>,
+    /// The warnings collected since the last `take_warnings` call.
+    warnings: Vec<era_solc::StandardJsonOutputError>,
+}
+
+impl Session {
+    ///
+    /// Compiles the Solidity project and links it with the session libraries.
+    ///
+    pub fn compile_solidity(
+        &mut self,
+        paths: &[PathBuf],
+    ) -> Result<EraVMBuild, Vec<era_solc::StandardJsonOutputError>> {
+        let solc_compiler = match self.solc_compiler {
+            Some(ref solc_compiler) => solc_compiler.to_owned(),
+            None => era_solc::Compiler::try_from_default().map_err(Self::error)?,
+        };
+        let solc_codegen =
+            era_solc::StandardJsonInputCodegen::new(&solc_compiler.version, self.codegen);
+
+        let mut solc_input = era_solc::StandardJsonInput::try_from_solidity_paths(
+            paths,
+            self.libraries.as_slice(),
+            self.remappings.clone(),
+            era_solc::StandardJsonInputOptimizer::default(),
+            self.codegen,
+            self.evm_version,
+            self.enable_eravm_extensions,
+            era_solc::StandardJsonInputSelection::new_required(solc_codegen),
+            era_solc::StandardJsonInputMetadata::new(
+                self.metadata_literal,
+                !self.append_cbor,
+                self.metadata_hash_type.to_string(),
+            ),
+            self.llvm_options.clone(),
+            self.suppressed_errors.clone(),
+            self.suppressed_warnings.clone(),
+            false,
+            false,
+        )
+        .map_err(Self::error)?;
+
+        let mut messages = Vec::new();
+        let mut solc_output = solc_compiler
+            .standard_json(
+                &mut solc_input,
+                &mut messages,
+                self.base_path.clone(),
+                self.include_paths.clone(),
+                self.allow_paths.clone(),
+            )
+            .map_err(Self::error)?;
+        self.check(&mut solc_output)?;
+
+        let linker_symbols = solc_input
+            .settings
+            .libraries
+            .as_linker_symbols()
+            .map_err(Self::error)?;
+
+        let project = Project::try_from_solc_output(
+            solc_input.settings.libraries,
+            solc_codegen,
+            &mut solc_output,
+            &solc_compiler,
+            self.debug_config.as_ref(),
+        )
+        .map_err(Self::error)?;
+        self.check(&mut solc_output)?;
+
+        self.compile_project(
+            project,
+            messages,
+            self.enable_eravm_extensions,
+            self.optimizer_settings.clone(),
+            linker_symbols,
+        )
+    }
+
+    ///
+    /// Compiles the Yul project and links it with the session libraries.
+    ///
+    /// If the `solc` compiler is set, it is used to validate the sources.
+    ///
+    pub fn compile_yul(
+        &mut self,
+        paths: &[PathBuf],
+    ) -> Result<EraVMBuild, Vec<era_solc::StandardJsonOutputError>> {
+        let libraries = era_compiler_common::Libraries::try_from(self.libraries.as_slice())
+            .map_err(Self::error)?;
+        let linker_symbols = libraries.as_linker_symbols().map_err(Self::error)?;
+
+        let mut messages = Vec::new();
+        let solc_version = match self.solc_compiler.to_owned() {
+            Some(solc_compiler) => {
+                if self.enable_eravm_extensions {
+                    return Err(Self::error(anyhow::anyhow!("Yul validation cannot be done if EraVM extensions are enabled. Consider compiling without `solc`.")));
+                }
+                let mut solc_output = solc_compiler
+                    .validate_yul_paths(paths, libraries.clone(), &mut messages)
+                    .map_err(Self::error)?;
+                self.check(&mut solc_output)?;
+                Some(solc_compiler.version)
+            }
+            None => None,
+        };
+
+        let project = Project::try_from_yul_paths(
+            paths,
+            libraries,
+            None,
+            solc_version.as_ref(),
+            self.debug_config.as_ref(),
+        )
+        .map_err(Self::error)?;
+
+        self.compile_project(
+            project,
+            messages,
+            self.enable_eravm_extensions,
+            self.optimizer_settings.clone(),
+            linker_symbols,
+        )
+    }
+
+    ///
+    /// Compiles the LLVM IR project and links it with the session libraries.
+    ///
+    pub fn compile_llvm_ir(
+        &mut self,
+        paths: &[PathBuf],
+    ) -> Result<EraVMBuild, Vec<era_solc::StandardJsonOutputError>> {
+        let libraries = era_compiler_common::Libraries::try_from(self.libraries.as_slice())
+            .map_err(Self::error)?;
+        let linker_symbols = libraries.as_linker_symbols().map_err(Self::error)?;
+
+        let project =
+            Project::try_from_llvm_ir_paths(paths, libraries, None).map_err(Self::error)?;
+
+        self.compile_project(
+            project,
+            vec![],
+            false,
+            self.optimizer_settings.clone(),
+            linker_symbols,
+        )
+    }
+
+    ///
+    /// Compiles the EraVM assembly project.
+    ///
+    pub fn compile_eravm_assembly(
+        &mut self,
+        paths: &[PathBuf],
+    ) -> Result<EraVMBuild, Vec<era_solc::StandardJsonOutputError>> {
+        let project = Project::try_from_eravm_assembly_paths(paths, None).map_err(Self::error)?;
+
+        self.compile_project(
+            project,
+            vec![],
+            false,
+            era_compiler_llvm_context::OptimizerSettings::none(),
+            BTreeMap::new(),
+        )
+    }
+
+    ///
+    /// Compiles the standard JSON input.
+    ///
+    /// Follows the standard JSON mode semantics: the settings in the input take precedence
+    /// over the session ones, and all errors are returned in the output.
+    ///
+    pub fn standard_json(
+        &mut self,
+        solc_input: era_solc::StandardJsonInput,
+    ) -> era_solc::StandardJsonOutput {
+        let prune_output = solc_input.settings.selection_to_prune();
+
+        let mut messages = Vec::new();
+        let mut solc_output = match crate::standard_json_eravm_output(
+            self.solc_compiler.clone(),
+            self.codegen,
+            self.enable_eravm_extensions,
+            false,
+            solc_input,
+            &mut messages,
+            self.base_path.clone(),
+            self.include_paths.clone(),
+            self.allow_paths.clone(),
+            self.debug_config.clone(),
+            self.backend,
+            self.cache.clone(),
+        ) {
+            Ok(solc_output) => solc_output,
+            Err(error) => {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    error, None, None,
+                ));
+                era_solc::StandardJsonOutput::new_with_messages(messages)
+            }
+        };
+        solc_output.prune(prune_output);
+        solc_output
+    }
+
+    ///
+    /// Returns the warnings collected since the last call.
+    ///
+    pub fn take_warnings(&mut self) -> Vec<era_solc::StandardJsonOutputError> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    ///
+    /// Compiles the project and links it with `linker_symbols`.
+    ///
+    fn compile_project(
+        &mut self,
+        project: Project,
+        mut messages: Vec<era_solc::StandardJsonOutputError>,
+        enable_eravm_extensions: bool,
+        optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+        linker_symbols: BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+    ) -> Result<EraVMBuild, Vec<era_solc::StandardJsonOutputError>> {
+        let mut build = project
+            .compile_to_eravm(
+                &mut messages,
+                enable_eravm_extensions,
+                self.metadata_hash_type,
+                self.append_cbor,
+                optimizer_settings,
+                self.llvm_options.clone(),
+                self.output_assembly,
+                false,
+                false,
+                false,
+                false,
+                false,
+                self.debug_config.clone(),
+                self.backend,
+                self.cache.as_ref(),
+            )
+            .map_err(Self::error)?;
+        self.check(&mut build)?;
+
+        let mut build = build.link(linker_symbols, false);
+        self.check(&mut build)?;
+        Ok(build)
+    }
+
+    ///
+    /// Moves the warnings of `collectable` to the session, and returns its errors, if any.
+    ///
+    fn check<C>(
+        &mut self,
+        collectable: &mut C,
+    ) -> Result<(), Vec<era_solc::StandardJsonOutputError>>
+    where
+        C: CollectableError,
+    {
+        self.warnings.extend(collectable.take_warnings());
+        if collectable.has_errors() {
+            return Err(collectable.errors().into_iter().cloned().collect());
+        }
+        Ok(())
+    }
+
+    ///
+    /// Converts a general error into the list of structured errors.
+    ///
+    fn error(error: anyhow::Error) -> Vec<era_solc::StandardJsonOutputError> {
+        vec![era_solc::StandardJsonOutputError::new_error(
+            error, None, None,
+        )]
+    }
+}
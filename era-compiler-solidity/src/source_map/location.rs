@@ -0,0 +1,65 @@
+//!
+//! The EraVM source map location.
+//!
+
+///
+/// The EraVM source map location.
+///
+/// Uses the `solc` conventions, where `-1` stands for an unknown value.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Location {
+    /// The source code identifier.
+    pub source: isize,
+    /// The source code location begin.
+    pub begin: isize,
+    /// The source code location end.
+    pub end: isize,
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Self {
+            source: -1,
+            begin: -1,
+            end: -1,
+        }
+    }
+}
+
+impl Location {
+    /// The `solc` Yul source location annotation tag.
+    pub const YUL_ANNOTATION_TAG: &'static str = "@src";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(source: isize, begin: isize, end: isize) -> Self {
+        Self { source, begin, end }
+    }
+
+    ///
+    /// Parses the last `@src <source>:<begin>:<end>` annotation in a Yul comment.
+    ///
+    pub fn try_from_yul_comment(comment: &str) -> Option<Self> {
+        let (_, annotation) = comment.rsplit_once(Self::YUL_ANNOTATION_TAG)?;
+        let annotation = annotation.split_whitespace().next()?;
+
+        let mut parts = annotation.splitn(3, ':');
+        let source = parts.next()?.parse().ok()?;
+        let begin = parts.next()?.parse().ok()?;
+        let end = parts.next()?.parse().ok()?;
+        Some(Self::new(source, begin, end))
+    }
+
+    ///
+    /// Returns the length of the location, or `-1` if it is unknown.
+    ///
+    pub fn length(&self) -> isize {
+        if self.begin < 0 || self.end < self.begin {
+            -1
+        } else {
+            self.end - self.begin
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//!
+//! The EraVM source map.
+//!
+
+pub mod location;
+
+use std::cell::Cell;
+
+use crate::evmla::assembly::instruction::Instruction;
+
+use self::location::Location;
+
+thread_local! {
+    /// Whether the front ends set LLVM debug locations for the contract being translated on this thread.
+    static IS_DEBUG_LOCATION_REQUIRED: Cell<bool> = const { Cell::new(false) };
+}
+
+///
+/// The EraVM source map.
+///
+/// The front ends set LLVM debug locations in their own coordinates: lines and columns of the
+/// Yul source code, or instruction offsets and code segments of the EVM legacy assembly.
+/// The source map resolves these coordinates into source code locations, and is applied to the
+/// `.loc` directives of the EraVM assembly to map each instruction to its source code.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SourceMap {
+    /// The source code locations, sorted by the debug locations they start at.
+    pub locations: Vec<((usize, usize), Location)>,
+}
+
+impl SourceMap {
+    /// The EVM legacy assembly deploy code debug location column.
+    pub const EVMLA_COLUMN_DEPLOY: usize = 1;
+
+    /// The EVM legacy assembly runtime code debug location column.
+    pub const EVMLA_COLUMN_RUNTIME: usize = 2;
+
+    ///
+    /// Runs the LLVM IR translation `f`, with the debug locations set only if `is_required`.
+    ///
+    /// The translation traits do not accept extra arguments, so the flag is kept for the
+    /// current thread, which the whole translation of a contract runs on.
+    ///
+    pub fn with_debug_locations<T>(is_required: bool, f: impl FnOnce() -> T) -> T {
+        let previous = IS_DEBUG_LOCATION_REQUIRED.with(|flag| flag.replace(is_required));
+        let result = f();
+        IS_DEBUG_LOCATION_REQUIRED.with(|flag| flag.set(previous));
+        result
+    }
+
+    ///
+    /// Whether the debug locations must be set during the current LLVM IR translation.
+    ///
+    pub fn is_debug_location_required() -> bool {
+        IS_DEBUG_LOCATION_REQUIRED.with(Cell::get)
+    }
+
+    ///
+    /// Creates the source map from the Yul comments with `solc` source location annotations.
+    ///
+    /// Each annotation applies to the code following it, until the next annotation.
+    ///
+    pub fn from_yul_comments(
+        comments: &[(era_yul::yul::lexer::token::location::Location, String)],
+    ) -> Self {
+        let locations = comments
+            .iter()
+            .filter_map(|(location, comment)| {
+                Location::try_from_yul_comment(comment.as_str())
+                    .map(|source_location| ((location.line, location.column), source_location))
+            })
+            .collect();
+        Self { locations }
+    }
+
+    ///
+    /// Creates the source map from the EVM legacy assembly deploy and runtime code instructions.
+    ///
+    pub fn from_evmla(deploy_code: &[Instruction], runtime_code: &[Instruction]) -> Self {
+        let mut locations = Vec::with_capacity(deploy_code.len() + runtime_code.len());
+        for (code_segment, instructions) in [
+            (era_compiler_common::CodeSegment::Deploy, deploy_code),
+            (era_compiler_common::CodeSegment::Runtime, runtime_code),
+        ] {
+            for (offset, instruction) in instructions.iter().enumerate() {
+                locations.push((
+                    Self::evmla_debug_location(code_segment, offset),
+                    Location::new(
+                        instruction.source.unwrap_or(-1),
+                        instruction.begin,
+                        instruction.end,
+                    ),
+                ));
+            }
+        }
+        locations.sort_by_key(|(debug_location, _)| *debug_location);
+        Self { locations }
+    }
+
+    ///
+    /// Returns the debug location of the EVM legacy assembly instruction at `offset` in `code_segment`.
+    ///
+    pub fn evmla_debug_location(
+        code_segment: era_compiler_common::CodeSegment,
+        offset: usize,
+    ) -> (usize, usize) {
+        let column = match code_segment {
+            era_compiler_common::CodeSegment::Deploy => Self::EVMLA_COLUMN_DEPLOY,
+            era_compiler_common::CodeSegment::Runtime => Self::EVMLA_COLUMN_RUNTIME,
+        };
+        (offset + 1, column)
+    }
+
+    ///
+    /// Resolves the debug location into the source code location in effect at it.
+    ///
+    pub fn resolve(&self, line: usize, column: usize) -> Option<Location> {
+        let index = self
+            .locations
+            .partition_point(|(debug_location, _)| *debug_location <= (line, column));
+        index.checked_sub(1).map(|index| self.locations[index].1)
+    }
+
+    ///
+    /// Maps the instructions of the EraVM assembly to source code locations.
+    ///
+    /// Returns the map in the compressed `solc` format with an entry for each instruction of the
+    /// code section, in the order they are laid out in the bytecode. Each entry is
+    /// `<begin>:<length>:<source>`, where values equal to the ones of the previous entry are omitted.
+    /// Instructions without a debug location are mapped to `-1:-1:-1`.
+    ///
+    /// Unlike the EVM source maps indexed by instruction, but applied to byte offsets, EraVM
+    /// instructions are 8 bytes long and the program counter counts instructions, so the entry
+    /// at index `N` describes the instruction at PC `N`, that is, at byte offset `N * 8`.
+    /// Labels, directives, and data sections do not produce entries.
+    ///
+    pub fn encode(&self, assembly: &str) -> String {
+        let mut entries = Vec::new();
+        let mut is_code = false;
+        let mut location = None;
+        for line in assembly.lines() {
+            let line = line.split(';').next().unwrap_or_default().trim();
+            if line.is_empty() || line.ends_with(':') {
+                continue;
+            }
+
+            if let Some(directive) = line.strip_prefix('.') {
+                let mut parts = directive.split_whitespace();
+                match parts.next() {
+                    Some("text") => is_code = true,
+                    Some("data" | "rodata" | "bss") => is_code = false,
+                    Some("section") => {
+                        is_code = parts
+                            .next()
+                            .is_some_and(|name| name.trim_matches('"').starts_with(".text"));
+                    }
+                    Some("loc") => {
+                        let mut numbers = parts
+                            .skip(1)
+                            .map(|part| part.parse::<usize>().unwrap_or_default());
+                        let line = numbers.next().unwrap_or_default();
+                        let column = numbers.next().unwrap_or_default();
+                        location = if line == 0 {
+                            None
+                        } else {
+                            self.resolve(line, column)
+                        };
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            if is_code {
+                entries.push(location.unwrap_or_default());
+            }
+        }
+
+        let mut previous: Option<[isize; 3]> = None;
+        entries
+            .into_iter()
+            .map(|location| {
+                let values = [location.begin, location.length(), location.source];
+                let mut parts = values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| match previous {
+                        Some(previous) if previous[index] == *value => String::new(),
+                        _ => value.to_string(),
+                    })
+                    .collect::<Vec<String>>();
+                while parts.last().is_some_and(|part| part.is_empty()) {
+                    parts.pop();
+                }
+                previous = Some(values);
+                parts.join(":")
+            })
+            .collect::<Vec<String>>()
+            .join(";")
+    }
+}
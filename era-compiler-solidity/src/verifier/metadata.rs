@@ -0,0 +1,152 @@
+//!
+//! The CBOR metadata appended to EraVM bytecode.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The CBOR metadata appended to EraVM bytecode.
+///
+/// The metadata is a CBOR map followed by its 2-byte big-endian length, which may be
+/// padded with zeros to the word boundary.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    /// The length of the bytecode preceding the metadata.
+    pub code_length: usize,
+    /// The metadata fields. Byte strings are hexadecimal, and `name:version` lists are
+    /// split into separate fields.
+    pub fields: BTreeMap<String, String>,
+}
+
+impl Metadata {
+    /// The maximum number of padding bytes after the metadata.
+    pub const MAX_PADDING: usize = era_compiler_common::BYTE_LENGTH_FIELD * 2;
+
+    ///
+    /// Finds and parses the metadata at the end of `bytecode`.
+    ///
+    /// Returns `None` if the bytecode does not end with CBOR metadata.
+    ///
+    pub fn parse(bytecode: &[u8]) -> Option<Self> {
+        for padding in 0..=Self::MAX_PADDING.min(bytecode.len()) {
+            let end = bytecode.len() - padding;
+            if bytecode[end..].iter().any(|byte| *byte != 0) {
+                break;
+            }
+            if end < 2 {
+                break;
+            }
+
+            let length = u16::from_be_bytes([bytecode[end - 2], bytecode[end - 1]]) as usize;
+            let Some(start) = (end - 2).checked_sub(length) else {
+                continue;
+            };
+            if length == 0 {
+                continue;
+            }
+            if let Some(fields) = Self::parse_map(&bytecode[start..end - 2]) {
+                return Some(Self {
+                    code_length: start,
+                    fields,
+                });
+            }
+        }
+        None
+    }
+
+    ///
+    /// Parses a CBOR map of text keys to text, byte string, or unsigned integer values.
+    ///
+    fn parse_map(data: &[u8]) -> Option<BTreeMap<String, String>> {
+        let mut offset = 0;
+        let (major, count) = Self::parse_header(data, &mut offset)?;
+        if major != 5 {
+            return None;
+        }
+
+        let mut fields = BTreeMap::new();
+        for _ in 0..count {
+            let (major, length) = Self::parse_header(data, &mut offset)?;
+            if major != 3 {
+                return None;
+            }
+            let key = Self::parse_text(data, &mut offset, length)?;
+
+            let (major, length) = Self::parse_header(data, &mut offset)?;
+            let value = match major {
+                0 => length.to_string(),
+                2 => {
+                    let end = offset.checked_add(length as usize)?;
+                    let bytes = data.get(offset..end)?;
+                    offset = end;
+                    hex::encode(bytes)
+                }
+                3 => Self::parse_text(data, &mut offset, length)?,
+                _ => return None,
+            };
+
+            let entries = value
+                .split(';')
+                .map(|entry| entry.split_once(':'))
+                .collect::<Option<Vec<(&str, &str)>>>();
+            match entries {
+                Some(entries) if major == 3 => {
+                    for (name, version) in entries.into_iter() {
+                        fields.insert(name.to_owned(), version.to_owned());
+                    }
+                }
+                _ => {
+                    fields.insert(key, value);
+                }
+            }
+        }
+
+        if offset != data.len() {
+            return None;
+        }
+        Some(fields)
+    }
+
+    ///
+    /// Parses a CBOR item header, returning the major type and the argument.
+    ///
+    fn parse_header(data: &[u8], offset: &mut usize) -> Option<(u8, u64)> {
+        let byte = *data.get(*offset)?;
+        *offset += 1;
+
+        let major = byte >> 5;
+        let argument = match byte & 0x1f {
+            info @ 0..=23 => info as u64,
+            24 => Self::parse_argument(data, offset, 1)?,
+            25 => Self::parse_argument(data, offset, 2)?,
+            26 => Self::parse_argument(data, offset, 4)?,
+            27 => Self::parse_argument(data, offset, 8)?,
+            _ => return None,
+        };
+        Some((major, argument))
+    }
+
+    ///
+    /// Parses a big-endian CBOR item argument of `size` bytes.
+    ///
+    fn parse_argument(data: &[u8], offset: &mut usize, size: usize) -> Option<u64> {
+        let bytes = data.get(*offset..*offset + size)?;
+        *offset += size;
+        Some(
+            bytes
+                .iter()
+                .fold(0u64, |argument, byte| (argument << 8) | (*byte as u64)),
+        )
+    }
+
+    ///
+    /// Parses a CBOR text string of `length` bytes.
+    ///
+    fn parse_text(data: &[u8], offset: &mut usize, length: u64) -> Option<String> {
+        let end = offset.checked_add(length as usize)?;
+        let bytes = data.get(*offset..end)?;
+        *offset = end;
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+}
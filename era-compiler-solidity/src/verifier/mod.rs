@@ -0,0 +1,189 @@
+//!
+//! The bytecode verifier.
+//!
+
+pub mod metadata;
+pub mod report;
+
+use std::collections::BTreeSet;
+
+use self::metadata::Metadata;
+use self::report::metadata_mismatch::MetadataMismatch as ReportMetadataMismatch;
+use self::report::range::Range as ReportRange;
+use self::report::status::Status as ReportStatus;
+use self::report::Report;
+
+///
+/// The bytecode verifier.
+///
+#[derive(Debug, Default)]
+pub struct Verifier {}
+
+impl Verifier {
+    ///
+    /// Verifies `bytecode` against the contracts of the recompiled `standard_json` output.
+    ///
+    /// If `ignore_metadata` is set, the CBOR metadata at the end of the bytecode is not compared.
+    ///
+    pub fn verify(
+        standard_json: &era_solc::StandardJsonOutput,
+        bytecode: &[u8],
+        ignore_metadata: bool,
+    ) -> anyhow::Result<Report> {
+        let mut candidates = Vec::new();
+        for (path, file) in standard_json.contracts.iter() {
+            for (name, contract) in file.iter() {
+                let Some(eravm) = contract.eravm.as_ref() else {
+                    continue;
+                };
+                if eravm.bytecode.is_empty() {
+                    continue;
+                }
+                let compiled = hex::decode(eravm.bytecode.as_str()).map_err(|error| {
+                    anyhow::anyhow!("Contract `{path}:{name}` bytecode decoding: {error}")
+                })?;
+                candidates.push((format!("{path}:{name}"), compiled));
+            }
+        }
+
+        let actual_metadata = Metadata::parse(bytecode);
+        let actual_code = Self::code(bytecode, actual_metadata.as_ref());
+
+        let mut closest: Option<(String, Vec<u8>, usize)> = None;
+        for (contract, compiled) in candidates.into_iter() {
+            let expected_metadata = Metadata::parse(compiled.as_slice());
+            let expected_code = Self::code(compiled.as_slice(), expected_metadata.as_ref());
+
+            let status = if compiled.as_slice() == bytecode {
+                ReportStatus::Exact
+            } else if expected_code == actual_code {
+                ReportStatus::Partial
+            } else {
+                ReportStatus::Mismatch
+            };
+            let verified = match status {
+                ReportStatus::Exact => true,
+                ReportStatus::Partial => ignore_metadata,
+                ReportStatus::Mismatch => false,
+            };
+            if status != ReportStatus::Mismatch {
+                let differences = if ignore_metadata {
+                    vec![]
+                } else {
+                    Self::differences(compiled.as_slice(), bytecode)
+                };
+                return Ok(Report {
+                    verified,
+                    status,
+                    contract: Some(contract),
+                    differences,
+                    metadata_mismatches: Self::metadata_mismatches(
+                        expected_metadata.as_ref(),
+                        actual_metadata.as_ref(),
+                    ),
+                });
+            }
+
+            let distance = Self::differences(expected_code, actual_code)
+                .iter()
+                .map(|range| range.end - range.start)
+                .sum::<usize>();
+            if closest
+                .as_ref()
+                .map(|(_contract, _compiled, closest_distance)| distance < *closest_distance)
+                .unwrap_or(true)
+            {
+                closest = Some((contract, compiled, distance));
+            }
+        }
+
+        let (contract, differences, metadata_mismatches) = match closest {
+            Some((contract, compiled, _distance)) => {
+                let expected_metadata = Metadata::parse(compiled.as_slice());
+                let differences = if ignore_metadata {
+                    Self::differences(
+                        Self::code(compiled.as_slice(), expected_metadata.as_ref()),
+                        actual_code,
+                    )
+                } else {
+                    Self::differences(compiled.as_slice(), bytecode)
+                };
+                let metadata_mismatches =
+                    Self::metadata_mismatches(expected_metadata.as_ref(), actual_metadata.as_ref());
+                (Some(contract), differences, metadata_mismatches)
+            }
+            None => (None, vec![], vec![]),
+        };
+        Ok(Report {
+            verified: false,
+            status: ReportStatus::Mismatch,
+            contract,
+            differences,
+            metadata_mismatches,
+        })
+    }
+
+    ///
+    /// Returns the bytecode without the CBOR metadata.
+    ///
+    fn code<'a>(bytecode: &'a [u8], metadata: Option<&Metadata>) -> &'a [u8] {
+        match metadata {
+            Some(metadata) => &bytecode[..metadata.code_length],
+            None => bytecode,
+        }
+    }
+
+    ///
+    /// Returns the ranges of differing bytes, including the tail of the longer bytecode.
+    ///
+    fn differences(expected: &[u8], actual: &[u8]) -> Vec<ReportRange> {
+        let mut differences: Vec<ReportRange> = Vec::new();
+        for offset in 0..expected.len().min(actual.len()) {
+            if expected[offset] == actual[offset] {
+                continue;
+            }
+            match differences.last_mut() {
+                Some(range) if range.end == offset => range.end += 1,
+                _ => differences.push(ReportRange::new(offset, offset + 1)),
+            }
+        }
+        if expected.len() != actual.len() {
+            let start = expected.len().min(actual.len());
+            let end = expected.len().max(actual.len());
+            match differences.last_mut() {
+                Some(range) if range.end == start => range.end = end,
+                _ => differences.push(ReportRange::new(start, end)),
+            }
+        }
+        differences
+    }
+
+    ///
+    /// Returns the CBOR metadata fields that differ.
+    ///
+    fn metadata_mismatches(
+        expected: Option<&Metadata>,
+        actual: Option<&Metadata>,
+    ) -> Vec<ReportMetadataMismatch> {
+        let fields: BTreeSet<&String> = expected
+            .iter()
+            .chain(actual.iter())
+            .flat_map(|metadata| metadata.fields.keys())
+            .collect();
+        fields
+            .into_iter()
+            .filter_map(|field| {
+                let expected = expected.and_then(|metadata| metadata.fields.get(field).cloned());
+                let actual = actual.and_then(|metadata| metadata.fields.get(field).cloned());
+                if expected == actual {
+                    return None;
+                }
+                Some(ReportMetadataMismatch::new(
+                    field.to_owned(),
+                    expected,
+                    actual,
+                ))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,29 @@
+//!
+//! The mismatched CBOR metadata field.
+//!
+
+///
+/// The mismatched CBOR metadata field.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct MetadataMismatch {
+    /// The field name, such as `solc`, `zksolc`, or `ipfs`.
+    pub field: String,
+    /// The value in the recompiled bytecode.
+    pub expected: Option<String>,
+    /// The value in the supplied bytecode.
+    pub actual: Option<String>,
+}
+
+impl MetadataMismatch {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(field: String, expected: Option<String>, actual: Option<String>) -> Self {
+        Self {
+            field,
+            expected,
+            actual,
+        }
+    }
+}
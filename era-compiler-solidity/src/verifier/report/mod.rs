@@ -0,0 +1,30 @@
+//!
+//! The bytecode verification report.
+//!
+
+pub mod metadata_mismatch;
+pub mod range;
+pub mod status;
+
+use self::metadata_mismatch::MetadataMismatch;
+use self::range::Range;
+use self::status::Status;
+
+///
+/// The bytecode verification report.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    /// Whether the bytecode is verified with the requested comparison mode.
+    pub verified: bool,
+    /// The verification status.
+    pub status: Status,
+    /// The matched contract, or the closest one if nothing matched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    /// The byte ranges where the supplied bytecode differs from the contract.
+    pub differences: Vec<Range>,
+    /// The CBOR metadata fields that differ between the supplied bytecode and the contract.
+    pub metadata_mismatches: Vec<MetadataMismatch>,
+}
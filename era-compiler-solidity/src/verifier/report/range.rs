@@ -0,0 +1,23 @@
+//!
+//! The differing bytecode byte range.
+//!
+
+///
+/// The differing bytecode byte range.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct Range {
+    /// The start offset, inclusive.
+    pub start: usize,
+    /// The end offset, exclusive.
+    pub end: usize,
+}
+
+impl Range {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
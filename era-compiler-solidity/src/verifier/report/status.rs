@@ -0,0 +1,17 @@
+//!
+//! The bytecode verification status.
+//!
+
+///
+/// The bytecode verification status.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// The bytecode matches exactly, including the CBOR metadata.
+    Exact,
+    /// The bytecode matches if the CBOR metadata is ignored.
+    Partial,
+    /// The bytecode does not match any contract.
+    Mismatch,
+}
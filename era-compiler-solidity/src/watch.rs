@@ -0,0 +1,277 @@
+//!
+//! The watch mode state.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::build_eravm::contract::Contract as EraVMContractBuild;
+use crate::build_eravm::Build as EraVMBuild;
+use crate::cache::Cache;
+use crate::process::backend::Backend as ProcessBackend;
+use crate::project::Project;
+
+///
+/// The watch mode state.
+///
+/// Keeps the unlinked builds of the previous run in memory, so only contracts whose IR
+/// has changed are sent to compilation again.
+///
+#[derive(Debug)]
+pub struct Watch {
+    /// The file polling interval.
+    pub interval: Duration,
+    /// The input files and remapping targets, which are watched even if compilation fails.
+    /// The directories among them are watched with all their subdirectories and Solidity files.
+    seed: BTreeSet<PathBuf>,
+    /// The watched files and their last modification times.
+    files: BTreeMap<PathBuf, Option<SystemTime>>,
+    /// The unlinked contract builds of the previous run with their fingerprints.
+    builds: BTreeMap<String, (String, EraVMContractBuild)>,
+}
+
+impl Watch {
+    /// The default file polling interval.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The input `paths` and the targets of `remappings` are watched from the start, so
+    /// changes are detected even if the first compilation fails before `solc` returns sources.
+    /// The Solidity files inside the remapped directories are watched as well.
+    ///
+    pub fn new(interval: Duration, paths: &[PathBuf], remappings: &BTreeSet<String>) -> Self {
+        let seed: BTreeSet<PathBuf> = paths
+            .iter()
+            .cloned()
+            .chain(remappings.iter().filter_map(|remapping| {
+                remapping
+                    .split_once('=')
+                    .map(|(_, target)| PathBuf::from(target))
+            }))
+            .collect();
+        let mut watch = Self {
+            interval,
+            seed,
+            files: BTreeMap::new(),
+            builds: BTreeMap::new(),
+        };
+        watch.files = watch
+            .seed_files()
+            .into_iter()
+            .map(|path| {
+                let modified = Self::modified(path.as_path());
+                (path, modified)
+            })
+            .collect();
+        watch
+    }
+
+    ///
+    /// Replaces the set of watched files with the input `paths` and their import closure
+    /// taken from the `solc` output AST.
+    ///
+    /// The input files and remapping targets passed to the constructor are always kept.
+    ///
+    pub fn watch_files(
+        &mut self,
+        paths: &[PathBuf],
+        solc_output: &era_solc::StandardJsonOutput,
+        base_path: Option<&str>,
+        include_paths: &[String],
+    ) {
+        let mut files = self.seed_files();
+        files.extend(paths.iter().cloned());
+        for (name, source) in solc_output.sources.iter() {
+            let source_unit_name = source.absolute_path().unwrap_or(name.as_str());
+            if let Some(path) = Self::resolve(source_unit_name, base_path, include_paths) {
+                files.insert(path);
+            }
+        }
+
+        self.files = files
+            .into_iter()
+            .map(|path| {
+                let modified = Self::modified(path.as_path());
+                (path, modified)
+            })
+            .collect();
+    }
+
+    ///
+    /// Blocks until any of the watched files is modified, created, or removed.
+    ///
+    pub fn wait_for_changes(&self) -> anyhow::Result<()> {
+        writeln!(
+            std::io::stderr(),
+            "Watching {} file(s) for changes...",
+            self.files.len()
+        )?;
+
+        loop {
+            std::thread::sleep(self.interval);
+            if self
+                .files
+                .iter()
+                .any(|(path, modified)| Self::modified(path.as_path()) != *modified)
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    ///
+    /// Compiles the contracts that have changed since the previous run, and takes
+    /// the rest from memory.
+    ///
+    pub fn compile_to_eravm(
+        &mut self,
+        mut project: Project,
+        messages: &mut Vec<era_solc::StandardJsonOutputError>,
+        enable_eravm_extensions: bool,
+        metadata_hash_type: era_compiler_common::MetadataHashType,
+        append_cbor: bool,
+        optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+        llvm_options: Vec<String>,
+        output_assembly: bool,
+        output_source_map: bool,
+        output_statistics: bool,
+        debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+        backend: ProcessBackend,
+        cache: Option<&Cache>,
+    ) -> anyhow::Result<EraVMBuild> {
+        let project_preimage = serde_json::to_vec(&(&project.identifier_paths, &project.libraries))
+            .expect("Always valid");
+        let fingerprints: BTreeMap<String, String> = project
+            .contracts
+            .iter()
+            .map(|(path, contract)| {
+                let mut preimage = project_preimage.clone();
+                preimage.extend(serde_json::to_vec(contract).expect("Always valid"));
+                let fingerprint =
+                    era_compiler_common::Keccak256Hash::from_slice(preimage.as_slice()).to_string();
+                (path.to_owned(), fingerprint)
+            })
+            .collect();
+
+        self.builds
+            .retain(|path, _| fingerprints.contains_key(path.as_str()));
+        let mut unchanged = BTreeMap::new();
+        project
+            .contracts
+            .retain(|path, _| match self.builds.get(path) {
+                Some((fingerprint, build)) if Some(fingerprint) == fingerprints.get(path) => {
+                    unchanged.insert(path.to_owned(), build.to_owned());
+                    false
+                }
+                _ => true,
+            });
+
+        writeln!(
+            std::io::stderr(),
+            "Compiling {} contract(s), {} unchanged.",
+            project.contracts.len(),
+            unchanged.len(),
+        )?;
+
+        let mut build = project.compile_to_eravm(
+            messages,
+            enable_eravm_extensions,
+            metadata_hash_type,
+            append_cbor,
+            optimizer_settings,
+            llvm_options,
+            output_assembly,
+            output_source_map,
+            false,
+            false,
+            false,
+            output_statistics,
+            debug_config,
+            backend,
+            cache,
+        )?;
+        for (path, result) in build.results.iter() {
+            if let Ok(contract) = result {
+                let fingerprint = fingerprints.get(path).cloned().expect("Always exists");
+                self.builds
+                    .insert(path.to_owned(), (fingerprint, contract.to_owned()));
+            }
+        }
+        build.results.extend(
+            unchanged
+                .into_iter()
+                .map(|(path, contract)| (path, Ok(contract))),
+        );
+        Ok(build)
+    }
+
+    ///
+    /// Returns the seed files, with the seed directories expanded.
+    ///
+    /// A directory modification time only changes when its entries are created or removed,
+    /// so the files inside are watched individually.
+    ///
+    fn seed_files(&self) -> BTreeSet<PathBuf> {
+        let mut files = BTreeSet::new();
+        for path in self.seed.iter() {
+            files.insert(path.to_owned());
+            Self::walk(path.as_path(), &mut files);
+        }
+        files
+    }
+
+    ///
+    /// Collects the subdirectories and Solidity files of the `directory` recursively.
+    ///
+    /// Symbolic links to directories are not followed to avoid cycles.
+    ///
+    fn walk(directory: &Path, files: &mut BTreeSet<PathBuf>) {
+        let Ok(entries) = std::fs::read_dir(directory) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            match entry.file_type() {
+                Ok(file_type) if file_type.is_dir() => {
+                    files.insert(path.clone());
+                    Self::walk(path.as_path(), files);
+                }
+                Ok(_) if path.extension().is_some_and(|extension| extension == "sol") => {
+                    files.insert(path);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    ///
+    /// Resolves the `solc` source unit name to a file system path, using the same lookup
+    /// order as `solc`: the base path first, and then the include paths.
+    ///
+    fn resolve(
+        source_unit_name: &str,
+        base_path: Option<&str>,
+        include_paths: &[String],
+    ) -> Option<PathBuf> {
+        std::iter::once(base_path.unwrap_or_default())
+            .chain(include_paths.iter().map(String::as_str))
+            .map(|directory| Path::new(directory).join(source_unit_name))
+            .find(|path| path.is_file())
+    }
+
+    ///
+    /// Returns the last modification time of the file, or `None` if it does not exist.
+    ///
+    fn modified(path: &Path) -> Option<SystemTime> {
+        std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+}
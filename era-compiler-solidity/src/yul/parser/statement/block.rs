@@ -6,6 +6,7 @@ use era_compiler_llvm_context::IContext;
 use era_yul::yul::parser::statement::Statement;
 
 use crate::declare_wrapper;
+use crate::source_map::SourceMap;
 use crate::yul::parser::dialect::era::EraDialect;
 use crate::yul::parser::wrapper::Wrap;
 
@@ -46,6 +47,11 @@ impl era_compiler_llvm_context::EraVMWriteLLVM for Block {
                 break;
             }
 
+            if SourceMap::is_debug_location_required() {
+                let location = statement.location();
+                context.set_debug_location(location.line as u32, location.column as u32, None)?;
+            }
+
             match statement {
                 Statement::Block(block) => {
                     block.wrap().into_llvm(context)?;
@@ -3,12 +3,17 @@
 //!
 
 use std::collections::BTreeSet;
+use std::ffi::OsString;
 use std::path::Path;
 use std::path::PathBuf;
 
+use clap::CommandFactory;
+use clap::FromArgMatches;
 use clap::Parser;
 use path_slash::PathExt;
 
+use crate::config::Config;
+
 ///
 /// Compiles the provided Solidity input files (or use the standard input if no files
 /// are given or "-" is specified as a file name). Outputs the components based on the
@@ -24,10 +29,22 @@ pub struct Arguments {
     #[arg(long)]
     pub version: bool,
 
+    /// Read the default values of options from the specified TOML file.
+    /// If not provided, `zksolc.toml` is read from the current directory, if it exists,
+    /// but only in basic Solidity and Yul modes.
+    /// Relative paths in the file are resolved against its directory.
+    /// Options passed via the command line take precedence.
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// Apply the options of the specified profile section of the config file.
+    /// Profile options take precedence over the top-level options of the config file.
+    #[arg(long)]
+    pub profile: Option<String>,
+
     /// Specify the input paths and remappings.
     /// If an argument contains a '=', it is considered a remapping.
-    /// Multiple Solidity files can be passed in the default Solidity mode.
-    /// Yul, LLVM IR, and EraVM Assembly modes currently support only a single file.
+    /// Multiple files can be passed in Solidity, Yul, LLVM IR, and EraVM assembly modes.
     pub inputs: Vec<String>,
 
     /// Set the given path as the root of the source tree instead of the root of the filesystem.
@@ -86,35 +103,88 @@ pub struct Arguments {
     #[arg(short, long, num_args = 1..)]
     pub libraries: Vec<String>,
 
+    /// Read addresses of deployable libraries from a JSON or TOML file, in addition to `--libraries`.
+    /// Both the nested `solc`-style `{"<path>": {"<name>": "<address>"}}` and the flat `{"<path>:<name>": "<address>"}` formats are accepted.
+    /// The TOML format is used for files with the `.toml` extension, and JSON otherwise.
+    #[arg(long)]
+    pub libraries_file: Option<PathBuf>,
+
+    /// Specify CREATE2 parameters of deployable libraries instead of addresses in linker mode.
+    /// Syntax: `<libraryFullPath1>=<deployer1>:<salt1> ... <libraryFullPathN>=<deployerN>:<saltN>`.
+    /// Library bytecode objects must be passed to the linker under their full paths, so their addresses
+    /// are computed from their bytecode hashes once they are linked.
+    #[arg(long, num_args = 1..)]
+    pub libraries_create2: Vec<String>,
+
+    /// Compile only the specified contract and its factory dependencies. Syntax: `<path>:<Name>`.
+    /// Can be used multiple times. Only available in Solidity mode.
+    #[arg(long = "contract")]
+    pub contracts: Vec<String>,
+
     /// Output a single JSON document containing the specified information.
     /// Available arguments: `abi`, `hashes`, `metadata`, `devdoc`, `userdoc`, `storage-layout`, `ast`, `asm`, `bin`, `bin-runtime`.
     #[arg(long)]
     pub combined_json: Option<String>,
 
+    /// Specify the combined JSON output profile.
+    /// Available options: `compatible` (default, mirrors the `solc` output), `strict` (drops EVM fields such as `bin-runtime`).
+    /// Only available in combined JSON mode.
+    #[arg(long)]
+    pub combined_json_profile: Option<era_solc::CombinedJsonProfile>,
+
+    /// Write a separate combined JSON file for each contract to `<output-dir>/<file name>/<contract name>.json`.
+    /// Only available in combined JSON mode, and requires `--output-dir`.
+    #[arg(long)]
+    pub combined_json_per_contract: bool,
+
     /// Switch to standard JSON input/output mode. Read from stdin or specified file, write the result to stdout.
     /// This is the default used by the Hardhat plugin.
     #[arg(long)]
     pub standard_json: Option<Option<String>>,
 
+    /// Verify the specified bytecode file against the project recompiled from the standard JSON input.
+    /// Both hexadecimal and raw binary bytecode files are accepted.
+    /// The verification report is returned via stdout. Only available in standard JSON mode.
+    #[arg(long)]
+    pub verify: Option<PathBuf>,
+
+    /// Ignore the CBOR metadata at the end of bytecode during verification.
+    /// Only available with `--verify`.
+    #[arg(long)]
+    pub ignore_metadata: bool,
+
+    /// Keep running and recompile whenever an input file or any of its imports changes.
+    /// Only contracts whose IR has changed are recompiled.
+    /// Only available in the default Solidity and combined JSON modes.
+    #[arg(long)]
+    pub watch: bool,
+
     /// Sets the number of threads, where each thread compiles its own translation unit in a child process.
     #[arg(short, long)]
     pub threads: Option<usize>,
 
+    /// Specify the contract compilation backend.
+    /// Available options: `subprocess`, `in-process`.
+    /// `subprocess` isolates each contract in a child process, while `in-process` compiles
+    /// contracts on worker threads of the current process, avoiding the process spawning overhead.
+    #[arg(long)]
+    pub backend: Option<era_compiler_solidity::ProcessBackend>,
+
     /// Switch to Yul mode.
-    /// Only one input Yul file is allowed.
+    /// Multiple input Yul files are allowed, and their objects may deploy each other.
     /// Cannot be used with combined and standard JSON modes.
     #[arg(long)]
     pub yul: bool,
 
     /// Switch to LLVM IR mode.
-    /// Only one input LLVM IR file is allowed.
+    /// Multiple input LLVM IR files are allowed.
     /// Cannot be used with combined and standard JSON modes.
     /// Use this mode at your own risk, as LLVM IR input validation is not implemented.
     #[arg(long)]
     pub llvm_ir: bool,
 
     /// Switch to EraVM assembly mode.
-    /// Only one input EraVM assembly file is allowed.
+    /// Multiple input EraVM assembly files are allowed.
     /// Cannot be used with combined and standard JSON modes.
     /// Use this mode at your own risk, as EraVM assembly input validation is not implemented.
     #[arg(long)]
@@ -128,10 +198,47 @@ pub struct Arguments {
 
     /// Specify the bytecode file to link.
     /// In default mode, input bytecode files and `--libraries` are required, and the input files are modified in place.
+    /// Both hexadecimal and raw binary bytecode files are accepted.
     /// In standard JSON mode, the result of linking is returned via stdout in a JSON.
     #[arg(long)]
     pub link: bool,
 
+    /// Link all contracts in the specified `zksolc` standard JSON output file.
+    /// Library addresses are taken from `--libraries`, and the linked standard JSON output is returned via stdout.
+    /// Only available in linker mode.
+    #[arg(long)]
+    pub standard_json_output: Option<PathBuf>,
+
+    /// Specify the format of linked bytecode files written back in linker mode.
+    /// Available options: `hex`, `binary`.
+    /// By default, each file is written in the format it was read in.
+    /// Only available in linker mode with bytecode files.
+    #[arg(long)]
+    pub link_output_format: Option<era_compiler_solidity::LinkerFileFormat>,
+
+    /// Write the link map describing every resolved library symbol and factory dependency to the specified file.
+    /// Only available in linker mode.
+    #[arg(long)]
+    pub link_map: Option<PathBuf>,
+
+    /// Write the factory dependency graph of the input bytecode files to the specified file.
+    /// The DOT format is used for files with the `.dot` extension, and JSON otherwise.
+    /// Only available in linker mode.
+    #[arg(long)]
+    pub dependency_graph: Option<PathBuf>,
+
+    /// Inspect the input unlinked bytecode files instead of linking them.
+    /// Offsets of library and factory dependency references in the linked bytecode are returned via stdout.
+    /// Only available in linker mode with bytecode files.
+    #[arg(long)]
+    pub inspect: bool,
+
+    /// Compare the only inspected bytecode file to the linked bytecode in the specified file,
+    /// and recover the library addresses and factory dependency hashes written in it.
+    /// Only available in linker inspection mode.
+    #[arg(long)]
+    pub inspect_linked: Option<PathBuf>,
+
     /// Specify the `solc` codegen.
     /// Available options: `evmla`, `yul`.
     #[arg(long)]
@@ -170,6 +277,35 @@ pub struct Arguments {
     #[arg(long = "bin")]
     pub output_binary: bool,
 
+    /// Specify the format of errors and warnings printed to stderr.
+    /// Available options: `human`, `json` (one JSON object per line), `sarif` (SARIF v2.1.0 log).
+    /// Not available in standard JSON mode, where diagnostics are returned in the output JSON.
+    #[arg(long)]
+    pub diagnostics_format: Option<era_compiler_solidity::DiagnosticsFormat>,
+
+    /// Print the bytecode size of each contract to stderr, and whether `--size-fallback` was triggered.
+    /// In standard JSON mode, the sizes are always returned in the `eravm.sizes` field.
+    #[arg(long)]
+    pub size_report: bool,
+
+    /// Fail the compilation if a linked contract exceeds the EraVM bytecode size limit.
+    /// By default, such contracts are only reported with warnings.
+    #[arg(long)]
+    pub fail_on_size_limit: bool,
+
+    /// Print the compilation statistics of each contract to stderr: wall time, LLVM IR generation
+    /// and LLVM time, peak memory of the subprocess, and function and instruction counts.
+    /// In standard JSON mode, use the `eravm.statistics` output selector instead.
+    #[arg(long)]
+    pub statistics: bool,
+
+    /// Output the deployment plan: libraries to deploy in order, factory dependencies to publish,
+    /// and pending library symbols of each contract.
+    /// Written to `deployment_plan.json` if `--output-dir` is specified, and to stdout otherwise.
+    /// In standard JSON mode, use the `eravm.deploymentPlan` output selector instead.
+    #[arg(long)]
+    pub deployment_plan: bool,
+
     /// Suppress specified errors.
     /// Available arguments: `sendtransfer`.
     #[arg(long, num_args = 1..)]
@@ -180,6 +316,12 @@ pub struct Arguments {
     #[arg(long, num_args = 1..)]
     pub suppress_warnings: Option<Vec<String>>,
 
+    /// Cache contract builds in the specified directory and reuse them in subsequent runs.
+    /// A contract is only recompiled if its IR, compilation settings, or the compiler version change.
+    /// In standard JSON mode, `settings.cacheDir` takes precedence over this option.
+    #[arg(long)]
+    pub cache_dir: Option<PathBuf>,
+
     /// Dump all IRs to files in the specified directory.
     /// Only for testing and debugging.
     #[arg(long)]
@@ -222,9 +364,75 @@ pub struct Arguments {
     /// The `solc` optimizer is not used by `zksolc` anymore.
     #[arg(long)]
     pub disable_solc_optimizer: bool,
+
+    /// The parsed argument list, with the config file options merged in.
+    #[arg(skip)]
+    pub raw_arguments: Vec<OsString>,
 }
 
 impl Arguments {
+    ///
+    /// Parses the command line arguments, taking the defaults from the config file.
+    ///
+    /// The config file options that are also set on the command line are dropped, so the latter
+    /// take precedence, except for list options, which are merged. The rest are prepended to the
+    /// command line ones, and both are validated together.
+    ///
+    pub fn try_parse_with_config() -> anyhow::Result<Self> {
+        let command_line: Vec<OsString> = std::env::args_os().collect();
+        let command_line_matches = Self::command().try_get_matches_from(command_line.iter())?;
+        let mut arguments = Self::from_arg_matches(&command_line_matches)?;
+
+        let path = match arguments.config {
+            _ if arguments.recursive_process => None,
+            Some(ref path) => Some(path.to_owned()),
+            None if arguments.is_basic_mode() && Path::new(Config::DEFAULT_PATH).is_file() => {
+                Some(PathBuf::from(Config::DEFAULT_PATH))
+            }
+            None if arguments.profile.is_some() => {
+                anyhow::bail!("Profiles can only be used with a config file.")
+            }
+            None => None,
+        };
+        let Some(path) = path else {
+            arguments.raw_arguments = command_line;
+            return Ok(arguments);
+        };
+        let config_arguments = Config::try_from_path(path.as_path())?
+            .into_arguments(arguments.profile.as_deref())
+            .map_err(|error| anyhow::anyhow!("Config file {path:?}: {error}"))?;
+
+        let command = Self::command();
+        let config_arguments = config_arguments.into_iter().filter(|argument| {
+            let argument = argument.to_string_lossy();
+            let Some(name) = argument
+                .strip_prefix("--")
+                .and_then(|argument| argument.split('=').next())
+            else {
+                return true;
+            };
+            let Some(option) = command
+                .get_arguments()
+                .find(|argument| argument.get_long() == Some(name))
+            else {
+                return true;
+            };
+            matches!(option.get_action(), clap::ArgAction::Append)
+                || command_line_matches.value_source(option.get_id().as_str())
+                    != Some(clap::parser::ValueSource::CommandLine)
+        });
+
+        let mut command_line = command_line.into_iter();
+        let executable = command_line.next().unwrap_or_default();
+        let raw_arguments: Vec<OsString> = std::iter::once(executable)
+            .chain(config_arguments)
+            .chain(command_line)
+            .collect();
+        let mut arguments = Self::try_parse_from(raw_arguments.iter())?;
+        arguments.raw_arguments = raw_arguments;
+        Ok(arguments)
+    }
+
     ///
     /// Validates the arguments.
     ///
@@ -252,7 +460,7 @@ impl Arguments {
             ));
         }
 
-        if self.version && std::env::args().count() > 2 {
+        if self.version && (!self.inputs.is_empty() || self.has_options_except(&["version"])) {
             messages.push(era_solc::StandardJsonOutputError::new_error(
                 "No other options are allowed while getting the compiler version.",
                 None,
@@ -344,7 +552,116 @@ impl Arguments {
             }
         }
 
-        if (self.llvm_ir || self.eravm_assembly || self.disassemble) && !self.libraries.is_empty() {
+        if self.watch
+            && (self.yul
+                || self.llvm_ir
+                || self.eravm_assembly
+                || self.disassemble
+                || self.link
+                || self.standard_json.is_some())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Watch mode is only supported in Solidity and combined JSON modes.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.disassemble || self.link) && self.cache_dir.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Build cache is not supported in disassembler and linker modes.",
+                None,
+                None,
+            ));
+        }
+
+        if self.standard_json.is_some() && self.diagnostics_format.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Diagnostics format is not supported in standard JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if !self.contracts.is_empty()
+            && (self.yul
+                || self.llvm_ir
+                || self.eravm_assembly
+                || self.disassemble
+                || self.link
+                || self.standard_json.is_some()
+                || self.combined_json.is_some())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Contract selection is only supported in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.size_report
+            && (self.disassemble
+                || self.link
+                || self.standard_json.is_some()
+                || self.combined_json.is_some())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Size report is only supported in Solidity, Yul, LLVM IR, and EraVM assembly modes.",
+                None,
+                None,
+            ));
+        }
+
+        if self.fail_on_size_limit
+            && (self.disassemble
+                || self.link
+                || self.standard_json.is_some()
+                || self.combined_json.is_some())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Failing on the size limit is only supported in Solidity, Yul, LLVM IR, and EraVM assembly modes.",
+                None,
+                None,
+            ));
+        }
+
+        if self.statistics
+            && (self.disassemble
+                || self.link
+                || self.standard_json.is_some()
+                || self.combined_json.is_some())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Compilation statistics are only supported in Solidity, Yul, LLVM IR, and EraVM assembly modes.",
+                None,
+                None,
+            ));
+        }
+
+        if self.deployment_plan
+            && (self.disassemble
+                || self.link
+                || self.standard_json.is_some()
+                || self.combined_json.is_some())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Deployment plan is only supported in Solidity, Yul, LLVM IR, and EraVM assembly modes.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.disassemble || self.link) && self.backend.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Compilation backend is not supported in disassembler and linker modes.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.llvm_ir || self.eravm_assembly || self.disassemble)
+            && (!self.libraries.is_empty() || self.libraries_file.is_some())
+        {
             messages.push(era_solc::StandardJsonOutputError::new_error(
                 "Libraries are only supported in Solidity, Yul, and linker modes.",
                 None,
@@ -369,7 +686,7 @@ impl Arguments {
             }
         }
 
-        if self.disassemble && std::env::args().count() > 2 + self.inputs.len() {
+        if self.disassemble && self.has_options_except(&["disassemble"]) {
             messages.push(era_solc::StandardJsonOutputError::new_error(
                 "No other options except input files are allowed in disassembler mode.",
                 None,
@@ -377,13 +694,21 @@ impl Arguments {
             ));
         }
 
-        let mut linker_default_arguments_count = 2;
-        linker_default_arguments_count += match self.standard_json {
-            Some(Some(_)) => 2,
-            Some(None) => 1,
-            _ => self.inputs.len() + ((!self.libraries.is_empty()) as usize) + self.libraries.len(),
-        };
-        if self.link && std::env::args().count() > linker_default_arguments_count {
+        if self.link
+            && self.has_options_except(&[
+                "link",
+                "libraries",
+                "libraries-create2",
+                "libraries-file",
+                "standard-json",
+                "standard-json-output",
+                "link-map",
+                "link-output-format",
+                "dependency-graph",
+                "inspect",
+                "inspect-linked",
+            ])
+        {
             messages.push(era_solc::StandardJsonOutputError::new_error(
                 "Error: No other options except bytecode files, `--libraries`, `--standard-json` are allowed in linker mode.",
                 None,
@@ -391,6 +716,84 @@ impl Arguments {
             ));
         }
 
+        if !self.link && self.link_map.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Link map is only supported in linker mode.",
+                None,
+                None,
+            ));
+        }
+
+        if !self.link && self.standard_json_output.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Standard JSON output linking is only supported in linker mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.standard_json_output.is_some()
+            && (self.standard_json.is_some() || !self.inputs.is_empty())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Standard JSON output linking cannot be used with `--standard-json` or bytecode files.",
+                None,
+                None,
+            ));
+        }
+
+        if (!self.link || self.standard_json.is_some() || self.standard_json_output.is_some())
+            && self.link_output_format.is_some()
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Linker output format is only supported in linker mode with bytecode files.",
+                None,
+                None,
+            ));
+        }
+
+        if !self.link && !self.libraries_create2.is_empty() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "CREATE2 libraries are only supported in linker mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.link && self.standard_json.is_some() && !self.libraries_create2.is_empty() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "CREATE2 libraries must be passed via linker JSON input.",
+                None,
+                None,
+            ));
+        }
+
+        if self.inspect
+            && (!self.link || self.standard_json.is_some() || self.standard_json_output.is_some())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Linker inspection is only supported in linker mode with bytecode files.",
+                None,
+                None,
+            ));
+        }
+
+        if self.inspect_linked.is_some() && (!self.inspect || self.inputs.len() != 1) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Comparison to linked bytecode is only supported in linker inspection mode with a single bytecode file.",
+                None,
+                None,
+            ));
+        }
+
+        if !self.link && self.dependency_graph.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Dependency graph is only supported in linker mode.",
+                None,
+                None,
+            ));
+        }
+
         if self.combined_json.is_some()
             && (self.output_assembly || self.output_metadata || self.output_binary)
         {
@@ -401,6 +804,40 @@ impl Arguments {
             ));
         }
 
+        if self.combined_json.is_none() && self.combined_json_profile.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Combined JSON profile is only supported in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.combined_json_per_contract
+            && (self.combined_json.is_none() || self.output_dir.is_none())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Per-contract combined JSON output is only supported in combined JSON mode with an output directory.",
+                None,
+                None,
+            ));
+        }
+
+        if self.verify.is_some() && (self.standard_json.is_none() || self.link) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Bytecode verification is only supported in standard JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.verify.is_none() && self.ignore_metadata {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Ignoring metadata is only supported in bytecode verification mode.",
+                None,
+                None,
+            ));
+        }
+
         if self.standard_json.is_none() && self.detect_missing_libraries {
             messages.push(era_solc::StandardJsonOutputError::new_error(
                 "Missing deployable libraries detection mode is only supported in standard JSON mode.", None, None
@@ -423,7 +860,7 @@ impl Arguments {
                     None,
                 ));
             }
-            if !self.libraries.is_empty() {
+            if !self.libraries.is_empty() || self.libraries_file.is_some() {
                 messages.push(era_solc::StandardJsonOutputError::new_error(
                     "Libraries must be passed via standard JSON input.",
                     None,
@@ -531,6 +968,54 @@ impl Arguments {
         messages
     }
 
+    ///
+    /// Checks whether the merged argument list contains options other than `allowed`.
+    ///
+    /// The config file options are checked as well, so invalid combinations are reported
+    /// the same way regardless of where the options come from. `--config` and `--profile`
+    /// are always allowed. The arguments after the `--` terminator are positional.
+    ///
+    fn has_options_except(&self, allowed: &[&str]) -> bool {
+        let command = Self::command();
+        self.raw_arguments
+            .iter()
+            .skip(1)
+            .map(|argument| argument.to_string_lossy())
+            .take_while(|argument| argument != "--")
+            .filter(|argument| argument.starts_with('-') && argument.len() > 1)
+            .any(|argument| {
+                let name = match argument.strip_prefix("--") {
+                    Some(name) => name.split('=').next().unwrap_or_default().to_owned(),
+                    None => argument
+                        .chars()
+                        .nth(1)
+                        .and_then(|short| {
+                            command
+                                .get_arguments()
+                                .find(|argument| argument.get_short() == Some(short))
+                                .and_then(|argument| argument.get_long())
+                        })
+                        .unwrap_or_default()
+                        .to_owned(),
+                };
+                !["config", "profile"].contains(&name.as_str()) && !allowed.contains(&name.as_str())
+            })
+    }
+
+    ///
+    /// Checks whether the basic Solidity or Yul mode is selected, where the default config file
+    /// is read implicitly.
+    ///
+    fn is_basic_mode(&self) -> bool {
+        !(self.version
+            || self.llvm_ir
+            || self.eravm_assembly
+            || self.disassemble
+            || self.link
+            || self.combined_json.is_some()
+            || self.standard_json.is_some())
+    }
+
     ///
     /// Returns remappings from input paths.
     ///
@@ -0,0 +1,164 @@
+//!
+//! Solidity compiler configuration file.
+//!
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::path::Path;
+use std::path::PathBuf;
+
+use clap::CommandFactory;
+
+use crate::arguments::Arguments;
+
+///
+/// Solidity compiler configuration file.
+///
+/// The top-level keys are the long names of the CLI options, e.g. `llvm-options` or `asm`.
+/// Sections of the `profile` table have the same format, and take precedence over the top-level keys
+/// if the profile is selected with `--profile`.
+///
+/// Relative paths are resolved against the directory of the configuration file.
+///
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct Config {
+    /// The import remappings, which are otherwise passed as positional arguments.
+    #[serde(default)]
+    pub remappings: Vec<String>,
+    /// The named profiles.
+    #[serde(default)]
+    pub profile: BTreeMap<String, BTreeMap<String, toml::Value>>,
+    /// The default values of the CLI options.
+    #[serde(flatten)]
+    pub options: BTreeMap<String, toml::Value>,
+    /// The directory of the configuration file.
+    #[serde(skip)]
+    pub directory: PathBuf,
+}
+
+impl Config {
+    /// The configuration file looked up in the current directory if `--config` is not specified.
+    pub const DEFAULT_PATH: &'static str = "zksolc.toml";
+
+    /// The options that cannot be set in the configuration file.
+    pub const FORBIDDEN_OPTIONS: [&'static str; 5] =
+        ["config", "profile", "recursive-process", "version", "help"];
+
+    /// The options whose values are file system paths.
+    pub const PATH_OPTIONS: [&'static str; 14] = [
+        "base-path",
+        "include-path",
+        "allow-paths",
+        "output-dir",
+        "solc",
+        "libraries-file",
+        "standard-json",
+        "verify",
+        "standard-json-output",
+        "link-map",
+        "dependency-graph",
+        "inspect-linked",
+        "cache-dir",
+        "debug-output-dir",
+    ];
+
+    ///
+    /// Reads and parses the configuration file.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let config = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Config file {path:?} reading: {error}"))?;
+        let mut config: Self = toml::from_str(config.as_str())
+            .map_err(|error| anyhow::anyhow!("Config file {path:?} parsing: {error}"))?;
+        config.directory = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Ok(config)
+    }
+
+    ///
+    /// Converts the configuration into the command line arguments, applying `profile` if specified.
+    ///
+    /// Positional arguments come first, and options are passed in the `--name=value` form,
+    /// so values starting with `-` are not mistaken for options.
+    ///
+    pub fn into_arguments(mut self, profile: Option<&str>) -> anyhow::Result<Vec<OsString>> {
+        if let Some(profile) = profile {
+            let options = self
+                .profile
+                .remove(profile)
+                .ok_or_else(|| anyhow::anyhow!("Profile `{profile}` is not found."))?;
+            self.options.extend(options);
+        }
+
+        let command = Arguments::command();
+        let mut arguments: Vec<OsString> = self
+            .remappings
+            .iter()
+            .map(|remapping| OsString::from(self.resolve("remappings", remapping)))
+            .collect();
+        for (name, value) in std::mem::take(&mut self.options).into_iter() {
+            if Self::FORBIDDEN_OPTIONS.contains(&name.as_str()) {
+                anyhow::bail!("Option `{name}` cannot be set in the config file.");
+            }
+            if !command
+                .get_arguments()
+                .any(|argument| argument.get_long() == Some(name.as_str()))
+            {
+                anyhow::bail!("Unknown option `{name}`.");
+            }
+
+            let values = match value {
+                toml::Value::Array(values) => values,
+                value => vec![value],
+            };
+            for value in values.into_iter() {
+                let argument = match value {
+                    toml::Value::Boolean(true) => format!("--{name}"),
+                    toml::Value::Boolean(false) => continue,
+                    toml::Value::String(value) => {
+                        format!("--{name}={}", self.resolve(name.as_str(), value.as_str()))
+                    }
+                    toml::Value::Integer(value) => format!("--{name}={value}"),
+                    toml::Value::Float(value) => format!("--{name}={value}"),
+                    value => anyhow::bail!(
+                        "Option `{name}` has an invalid value `{value}`: expected a boolean, string, number, or array."
+                    ),
+                };
+                arguments.push(OsString::from(argument));
+            }
+        }
+        Ok(arguments)
+    }
+
+    ///
+    /// Resolves relative paths in the value of the option `name` against the config file directory.
+    ///
+    /// Remapping targets and each of the comma-separated allowed paths are resolved separately.
+    /// The `solc` executable is only resolved if it is specified as a path rather than a name
+    /// to be looked up in `PATH`.
+    ///
+    fn resolve(&self, name: &str, value: &str) -> String {
+        let resolve_path = |path: &str| -> String {
+            if path.is_empty() || Path::new(path).is_absolute() {
+                path.to_owned()
+            } else {
+                self.directory.join(path).to_string_lossy().to_string()
+            }
+        };
+        match name {
+            "remappings" => match value.split_once('=') {
+                Some((prefix, target)) => format!("{prefix}={}", resolve_path(target)),
+                None => value.to_owned(),
+            },
+            "allow-paths" => value
+                .split(',')
+                .map(resolve_path)
+                .collect::<Vec<String>>()
+                .join(","),
+            "solc" if !value.contains(std::path::MAIN_SEPARATOR) && !value.contains('/') => {
+                value.to_owned()
+            }
+            name if Self::PATH_OPTIONS.contains(&name) => resolve_path(value),
+            _ => value.to_owned(),
+        }
+    }
+}
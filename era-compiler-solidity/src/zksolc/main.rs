@@ -3,24 +3,28 @@
 //!
 
 pub mod arguments;
+pub mod config;
 
 use std::io::Write;
 use std::path::PathBuf;
 use std::str::FromStr;
 
-use clap::Parser;
-
 use self::arguments::Arguments;
 
 ///
 /// The application entry point.
 ///
 fn main() -> anyhow::Result<()> {
-    let arguments = Arguments::try_parse()?;
+    let arguments = Arguments::try_parse_with_config()?;
     let is_standard_json = arguments.standard_json.is_some();
+    let diagnostics_format = arguments.diagnostics_format.unwrap_or_default();
+    let base_path = arguments.base_path.clone();
+    let include_paths = arguments.include_path.clone();
     let mut messages = arguments.validate();
     if messages.iter().all(|error| error.severity != "error") {
-        if !is_standard_json {
+        if !is_standard_json
+            && diagnostics_format == era_compiler_solidity::DiagnosticsFormat::Human
+        {
             std::io::stderr()
                 .write_all(
                     messages
@@ -33,9 +37,7 @@ fn main() -> anyhow::Result<()> {
                 .expect("Stderr writing error");
         }
         if let Err(error) = main_inner(arguments, &mut messages) {
-            messages.push(era_solc::StandardJsonOutputError::new_error(
-                error, None, None,
-            ));
+            push_error(&mut messages, error);
         }
     }
 
@@ -49,16 +51,14 @@ fn main() -> anyhow::Result<()> {
     } else {
         era_compiler_common::EXIT_CODE_SUCCESS
     };
-    std::io::stderr()
-        .write_all(
-            messages
-                .into_iter()
-                .map(|error| error.to_string())
-                .collect::<Vec<String>>()
-                .join("\n")
-                .as_bytes(),
-        )
-        .expect("Stderr writing error");
+    era_compiler_solidity::write_diagnostics(
+        &mut std::io::stderr(),
+        messages.as_slice(),
+        diagnostics_format,
+        base_path.as_deref(),
+        include_paths.as_slice(),
+    )
+    .expect("Stderr writing error");
     std::process::exit(exit_code);
 }
 
@@ -97,12 +97,75 @@ fn main_inner(
 
     inkwell::support::enable_llvm_pretty_stack_trace();
 
-    let (input_files, remappings) = arguments.split_input_files_and_remappings()?;
-
     if arguments.force_evmla {
         arguments.codegen = Some(era_solc::StandardJsonInputCodegen::EVMLA);
     }
 
+    if let Some(ref path) = arguments.libraries_file {
+        let libraries_file = era_compiler_solidity::LibrariesFile::try_from_path(path.as_path())?;
+        messages.extend(libraries_file.warnings(arguments.libraries.as_slice()));
+        arguments.libraries.extend(libraries_file.to_arguments());
+    }
+
+    if arguments.watch {
+        let (input_files, remappings) = arguments.split_input_files_and_remappings()?;
+        let mut watch = era_compiler_solidity::Watch::new(
+            era_compiler_solidity::Watch::DEFAULT_INTERVAL,
+            input_files.as_slice(),
+            &remappings,
+        );
+        loop {
+            if let Err(error) = compile(&arguments, messages, Some(&mut watch)) {
+                push_error(messages, error);
+            }
+            era_compiler_solidity::write_diagnostics(
+                &mut std::io::stderr(),
+                messages.as_slice(),
+                arguments.diagnostics_format.unwrap_or_default(),
+                arguments.base_path.as_deref(),
+                arguments.include_path.as_slice(),
+            )?;
+            messages.clear();
+            watch.wait_for_changes()?;
+        }
+    }
+
+    compile(&arguments, messages, None)
+}
+
+///
+/// Adds the error returned from compilation to the messages.
+///
+/// Compilation errors are collected to the messages before bailing with all of them joined,
+/// so the returned error is skipped only if it repeats the last collected errors.
+///
+fn push_error(messages: &mut Vec<era_solc::StandardJsonOutputError>, error: anyhow::Error) {
+    let error = error.to_string();
+    let collected: Vec<String> = messages
+        .iter()
+        .filter(|message| message.severity == "error")
+        .map(|message| message.to_string())
+        .collect();
+    if (0..collected.len()).any(|start| collected[start..].join("\n") == error) {
+        return;
+    }
+    messages.push(era_solc::StandardJsonOutputError::new_error(
+        error, None, None,
+    ));
+}
+
+///
+/// Compiles the input in the mode chosen by the arguments, and writes the output.
+///
+/// In watch mode, the function is called on every change of the watched files.
+///
+fn compile(
+    arguments: &Arguments,
+    messages: &mut Vec<era_solc::StandardJsonOutputError>,
+    watch: Option<&mut era_compiler_solidity::Watch>,
+) -> anyhow::Result<()> {
+    let (input_files, remappings) = arguments.split_input_files_and_remappings()?;
+
     let mut optimizer_settings = match arguments.optimization {
         Some(mode) => era_compiler_llvm_context::OptimizerSettings::try_from_cli(mode)?,
         None => era_compiler_llvm_context::OptimizerSettings::cycles(),
@@ -125,10 +188,18 @@ fn main_inner(
         .unwrap_or_default();
 
     let suppressed_errors = era_solc::StandardJsonInputErrorType::try_from_strings(
-        arguments.suppress_errors.unwrap_or_default().as_slice(),
+        arguments
+            .suppress_errors
+            .clone()
+            .unwrap_or_default()
+            .as_slice(),
     )?;
     let suppressed_warnings = era_solc::StandardJsonInputWarningType::try_from_strings(
-        arguments.suppress_warnings.unwrap_or_default().as_slice(),
+        arguments
+            .suppress_warnings
+            .clone()
+            .unwrap_or_default()
+            .as_slice(),
     )?;
 
     let debug_config = match arguments.debug_output_dir {
@@ -141,6 +212,15 @@ fn main_inner(
         None => None,
     };
 
+    let cache = match arguments.cache_dir {
+        Some(ref cache_directory) => Some(era_compiler_solidity::Cache::try_new(
+            cache_directory.to_owned(),
+        )?),
+        None => None,
+    };
+
+    let backend = arguments.backend.unwrap_or_default();
+
     let enable_eravm_extensions = arguments.enable_eravm_extensions || arguments.system_mode;
 
     let append_cbor = !arguments.no_cbor_metadata;
@@ -154,7 +234,7 @@ fn main_inner(
         era_compiler_solidity::yul_to_eravm(
             input_files.as_slice(),
             arguments.libraries.as_slice(),
-            arguments.solc,
+            arguments.solc.clone(),
             messages,
             enable_eravm_extensions,
             metadata_hash_type,
@@ -162,7 +242,11 @@ fn main_inner(
             optimizer_settings,
             llvm_options,
             arguments.output_assembly,
+            arguments.statistics,
+            arguments.fail_on_size_limit,
             debug_config,
+            backend,
+            cache,
         )
     } else if arguments.llvm_ir {
         era_compiler_solidity::llvm_ir_to_eravm(
@@ -174,7 +258,11 @@ fn main_inner(
             optimizer_settings,
             llvm_options,
             arguments.output_assembly,
+            arguments.statistics,
+            arguments.fail_on_size_limit,
             debug_config,
+            backend,
+            cache,
         )
     } else if arguments.eravm_assembly {
         era_compiler_solidity::eravm_assembly_to_eravm(
@@ -184,20 +272,77 @@ fn main_inner(
             append_cbor,
             llvm_options,
             arguments.output_assembly,
+            arguments.statistics,
+            arguments.fail_on_size_limit,
             debug_config,
+            backend,
+            cache,
         )
     } else if arguments.disassemble {
-        return era_compiler_solidity::disassemble_eravm(arguments.inputs);
+        return era_compiler_solidity::disassemble_eravm(arguments.inputs.clone());
     } else if arguments.link {
-        return match arguments.standard_json {
-            Some(path) => era_compiler_solidity::link_eravm_json(path),
-            None => era_compiler_solidity::link_eravm(arguments.inputs, arguments.libraries),
+        // the linker modes exit on their own, so the pending warnings are written beforehand
+        if !messages.is_empty() {
+            era_compiler_solidity::write_diagnostics(
+                &mut std::io::stderr(),
+                messages.as_slice(),
+                arguments.diagnostics_format.unwrap_or_default(),
+                arguments.base_path.as_deref(),
+                arguments.include_path.as_slice(),
+            )?;
+            messages.clear();
+        }
+
+        if arguments.inspect {
+            return era_compiler_solidity::inspect_eravm(
+                arguments.inputs.clone(),
+                arguments.inspect_linked.clone(),
+            );
+        }
+        if let Some(path) = arguments.standard_json_output.clone() {
+            return era_compiler_solidity::link_eravm_standard_json_output(
+                path,
+                arguments.libraries.clone(),
+                arguments.libraries_create2.clone(),
+                arguments.link_map.clone(),
+                arguments.dependency_graph.clone(),
+            );
+        }
+        return match arguments.standard_json.clone() {
+            Some(path) => era_compiler_solidity::link_eravm_json(
+                path,
+                arguments.link_map.clone(),
+                arguments.dependency_graph.clone(),
+            ),
+            None => era_compiler_solidity::link_eravm(
+                arguments.inputs.clone(),
+                arguments.libraries.clone(),
+                arguments.libraries_create2.clone(),
+                arguments.link_output_format,
+                arguments.link_map.clone(),
+                arguments.dependency_graph.clone(),
+            ),
         };
-    } else if let Some(standard_json) = arguments.standard_json {
+    } else if let Some(standard_json) = arguments.standard_json.clone() {
         let solc_compiler = match arguments.solc.as_deref() {
             Some(executable) => Some(era_solc::Compiler::try_from_path(executable)?),
             None => None,
         };
+        if let Some(bytecode_path) = arguments.verify.clone() {
+            return era_compiler_solidity::verify_eravm(
+                bytecode_path,
+                arguments.ignore_metadata,
+                solc_compiler,
+                arguments.codegen,
+                enable_eravm_extensions,
+                standard_json.map(PathBuf::from),
+                messages,
+                arguments.base_path.clone(),
+                arguments.include_path.clone(),
+                arguments.allow_paths.clone(),
+                backend,
+            );
+        }
         return era_compiler_solidity::standard_json_eravm(
             solc_compiler,
             arguments.codegen,
@@ -205,12 +350,14 @@ fn main_inner(
             arguments.detect_missing_libraries,
             standard_json.map(PathBuf::from),
             messages,
-            arguments.base_path,
-            arguments.include_path,
-            arguments.allow_paths,
+            arguments.base_path.clone(),
+            arguments.include_path.clone(),
+            arguments.allow_paths.clone(),
             debug_config,
+            backend,
+            cache,
         );
-    } else if let Some(format) = arguments.combined_json {
+    } else if let Some(format) = arguments.combined_json.clone() {
         let solc_compiler = era_solc::Compiler::try_from_path(
             arguments
                 .solc
@@ -229,17 +376,22 @@ fn main_inner(
             metadata_hash_type,
             append_cbor,
             arguments.metadata_literal,
-            arguments.base_path,
-            arguments.include_path,
-            arguments.allow_paths,
+            arguments.base_path.clone(),
+            arguments.include_path.clone(),
+            arguments.allow_paths.clone(),
             remappings,
-            arguments.output_dir,
-            arguments.overwrite,
+            arguments.output_dir.clone(),
+            arguments.overwrite || arguments.watch,
+            arguments.combined_json_profile.unwrap_or_default(),
+            arguments.combined_json_per_contract,
             optimizer_settings,
             llvm_options,
             suppressed_errors,
             suppressed_warnings,
             debug_config,
+            backend,
+            cache,
+            watch,
         );
     } else {
         let solc_compiler = era_solc::Compiler::try_from_path(
@@ -250,6 +402,7 @@ fn main_inner(
         )?;
         era_compiler_solidity::standard_output_eravm(
             input_files.as_slice(),
+            &arguments.contracts.iter().cloned().collect(),
             arguments.libraries.as_slice(),
             &solc_compiler,
             messages,
@@ -259,33 +412,60 @@ fn main_inner(
             metadata_hash_type,
             append_cbor,
             arguments.metadata_literal,
-            arguments.base_path,
-            arguments.include_path,
-            arguments.allow_paths,
+            arguments.base_path.clone(),
+            arguments.include_path.clone(),
+            arguments.allow_paths.clone(),
             remappings,
             optimizer_settings,
             llvm_options,
             arguments.output_assembly,
+            false,
+            arguments.statistics,
+            arguments.fail_on_size_limit,
             suppressed_errors,
             suppressed_warnings,
             debug_config,
+            backend,
+            cache,
+            watch,
         )
     }?;
 
-    if let Some(output_directory) = arguments.output_dir {
+    if arguments.size_report {
+        build.write_size_report()?;
+    }
+    if arguments.statistics {
+        build.write_statistics()?;
+    }
+    let deployment_plan = if arguments.deployment_plan {
+        Some(build.deployment_plan())
+    } else {
+        None
+    };
+
+    if let Some(ref output_directory) = arguments.output_dir {
         build.write_to_directory(
-            &output_directory,
+            messages,
+            output_directory,
             arguments.output_metadata,
             arguments.output_assembly,
             arguments.output_binary,
-            arguments.overwrite,
+            arguments.overwrite || arguments.watch,
         )?;
+        if let Some(deployment_plan) = deployment_plan {
+            deployment_plan
+                .write_to_directory(output_directory, arguments.overwrite || arguments.watch)?;
+        }
     } else {
         build.write_to_terminal(
+            messages,
             arguments.output_metadata,
             arguments.output_assembly,
             arguments.output_binary,
         )?;
+        if let Some(deployment_plan) = deployment_plan {
+            deployment_plan.write_to_terminal()?;
+        }
     }
 
     Ok(())
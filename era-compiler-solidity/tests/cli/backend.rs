@@ -0,0 +1,82 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn in_process() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--backend",
+        "in-process",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn in_process_same_as_subprocess() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut outputs = Vec::with_capacity(2);
+    for backend in ["subprocess", "in-process"] {
+        let args = &[
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+            "--bin",
+            "--backend",
+            backend,
+        ];
+
+        let result = crate::cli::execute_zksolc(args)?;
+        outputs.push(result.success().get_output().stdout.clone());
+    }
+    assert_eq!(outputs[0], outputs[1]);
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--backend",
+        "thread",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid compilation backend"));
+
+    Ok(())
+}
+
+#[test]
+fn link() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--link",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        "--backend",
+        "in-process",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Compilation backend is not supported in disassembler and linker modes.",
+    ));
+
+    Ok(())
+}
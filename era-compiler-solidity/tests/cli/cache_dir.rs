@@ -0,0 +1,110 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("zksolc_cache")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--cache-dir",
+        tmp_dir_cache.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let cold_stdout = result.success().get_output().stdout.clone();
+    assert!(std::fs::read_dir(tmp_dir_cache.path())?.next().is_some());
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let warm_stdout = result.success().get_output().stdout.clone();
+    assert_eq!(cold_stdout, warm_stdout);
+
+    Ok(())
+}
+
+#[test]
+fn settings_changed() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("zksolc_cache")?;
+
+    for optimization in ["-O1", "-O3"] {
+        let args = &[
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+            "--bin",
+            optimization,
+            "--cache-dir",
+            tmp_dir_cache.path().to_str().unwrap(),
+        ];
+
+        let result = crate::cli::execute_zksolc(args)?;
+        result.success();
+    }
+    assert_eq!(std::fs::read_dir(tmp_dir_cache.path())?.count(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn unrelated_contract_changed() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("zksolc_cache")?;
+    let tmp_dir_sources = TempDir::with_prefix("zksolc_sources")?;
+
+    let path_first = tmp_dir_sources.path().join("A.sol");
+    let path_second = tmp_dir_sources.path().join("B.sol");
+    std::fs::write(
+        path_first.as_path(),
+        "// SPDX-License-Identifier: MIT\npragma solidity >=0.4.16;\ncontract A {}\n",
+    )?;
+
+    for name in ["B", "C"] {
+        std::fs::write(
+            path_second.as_path(),
+            format!("// SPDX-License-Identifier: MIT\npragma solidity >=0.4.16;\ncontract {name} {{}}\n"),
+        )?;
+
+        let args = &[
+            path_first.to_str().unwrap(),
+            path_second.to_str().unwrap(),
+            "--bin",
+            "--cache-dir",
+            tmp_dir_cache.path().to_str().unwrap(),
+        ];
+
+        let result = crate::cli::execute_zksolc(args)?;
+        result.success();
+    }
+    assert_eq!(std::fs::read_dir(tmp_dir_cache.path())?.count(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn link() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("zksolc_cache")?;
+
+    let args = &[
+        "--link",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        "--cache-dir",
+        tmp_dir_cache.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Build cache is not supported in disassembler and linker modes.",
+    ));
+
+    Ok(())
+}
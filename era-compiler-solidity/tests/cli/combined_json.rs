@@ -3,6 +3,7 @@
 //!
 
 use predicates::prelude::*;
+use tempfile::TempDir;
 use test_case::test_case;
 
 const JSON_ARGS: &[&str] = &[
@@ -109,6 +110,208 @@ fn two_files() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn eravm_hash() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::is_match(r#""eravm-hash":"[0-9a-f]{64}""#).expect("Always valid"));
+
+    Ok(())
+}
+
+#[test]
+fn eravm_metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "metadata,bin",
+        "--metadata-hash",
+        "keccak256",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            r#""eravm-metadata":{"hash-type":"keccak256""#,
+        ))
+        .stdout(predicate::str::contains(r#""zksolc":""#))
+        .stdout(predicate::str::is_match(r#""keccak256":"[0-9a-f]{64}""#).expect("Always valid"))
+        .stdout(predicate::str::contains(r#""optimizer-settings":"#));
+
+    Ok(())
+}
+
+#[test]
+fn eravm_metadata_omitted() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("eravm-metadata").not());
+
+    Ok(())
+}
+
+#[test]
+fn profile_strict() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin,bin-runtime",
+        "--combined-json-profile",
+        "strict",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""bin":"#))
+        .stdout(predicate::str::contains("bin-runtime").not())
+        .stderr(predicate::str::contains(format!(
+            "The `{}` selector is ignored in the `{}` combined JSON profile.",
+            era_solc::CombinedJsonSelector::BytecodeRuntime,
+            era_solc::CombinedJsonProfile::Strict,
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn profile_compatible() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin,bin-runtime",
+        "--combined-json-profile",
+        "compatible",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""bin-runtime":"#));
+
+    Ok(())
+}
+
+#[test]
+fn profile_invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--combined-json-profile",
+        "unknown",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid combined JSON profile"));
+
+    Ok(())
+}
+
+#[test]
+fn profile_excess_mode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--combined-json-profile",
+        "strict",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Combined JSON profile is only supported in combined JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn per_contract() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_zksolc = TempDir::with_prefix("zksolc_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_GREETER_PATH,
+        "--combined-json",
+        "abi,bin",
+        "--combined-json-per-contract",
+        "--output-dir",
+        tmp_dir_zksolc.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let contract_path = tmp_dir_zksolc
+        .path()
+        .join(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+        .join("Test.json");
+    let combined_json: era_solc::CombinedJson =
+        serde_json::from_slice(std::fs::read(contract_path)?.as_slice())?;
+    assert_eq!(combined_json.contracts.len(), 1);
+    assert!(combined_json
+        .contracts
+        .keys()
+        .all(|full_path| full_path.ends_with("Test.sol:Test")));
+    assert!(!tmp_dir_zksolc.path().join("combined.json").exists());
+
+    Ok(())
+}
+
+#[test]
+fn per_contract_output_dir_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--combined-json-per-contract",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Per-contract combined JSON output is only supported in combined JSON mode with an output directory.",
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn invalid_path() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -0,0 +1,264 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::process::Command;
+
+use assert_cmd::assert::OutputAssertExt;
+use assert_cmd::cargo::CommandCargoExt;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+///
+/// Writes the config file to a temporary directory and returns both.
+///
+fn write_config(content: &str) -> anyhow::Result<(TempDir, String)> {
+    let tmp_dir = TempDir::with_prefix("zksolc_config")?;
+    let path = tmp_dir.path().join("zksolc.toml");
+    std::fs::write(path.as_path(), content)?;
+    Ok((tmp_dir, path.to_string_lossy().to_string()))
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config(
+        r#"
+bin = true
+optimization = "z"
+llvm-options = "-eravm-jump-table-density-threshold=10"
+"#,
+    )?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn command_line_precedence() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config(
+        r#"
+bin = true
+solc = "/non/existent/solc"
+"#,
+    )?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+        "--solc",
+        era_solc::Compiler::DEFAULT_EXECUTABLE_NAME,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn profile() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config(
+        r#"
+bin = true
+optimization = "3"
+
+[profile.release]
+optimization = "z"
+size-fallback = true
+"#,
+    )?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+        "--profile",
+        "release",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn profile_not_found() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config("bin = true\n")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+        "--profile",
+        "release",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Profile `release` is not found."));
+
+    Ok(())
+}
+
+#[test]
+fn unknown_option() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config("optimize = true\n")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Unknown option `optimize`."));
+
+    Ok(())
+}
+
+#[test]
+fn validated_with_command_line() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config("cache-dir = \"cache\"\n")?;
+
+    let args = &[
+        "--link",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Build cache is not supported in disassembler and linker modes.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn profile_without_config() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--profile",
+        "release",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Profiles can only be used with a config file.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn validated_version() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config("bin = true\n")?;
+
+    let args = &["--version", "--config", config_path.as_str()];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "No other options are allowed while getting the compiler version.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn validated_linker() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (_tmp_dir, config_path) = write_config("bin = true\n")?;
+
+    let args = &[
+        "--link",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "No other options except bytecode files, `--libraries`, `--standard-json` are allowed in linker mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn implicit_only_in_basic_mode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (tmp_dir, _config_path) = write_config("optimize = true\n")?;
+
+    let mut command = Command::cargo_bin(era_compiler_solidity::DEFAULT_EXECUTABLE_NAME)?;
+    command
+        .current_dir(tmp_dir.path())
+        .arg("--version")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[test]
+fn relative_paths() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (tmp_dir, config_path) = write_config(
+        r#"
+bin = true
+output-dir = "build"
+"#,
+    )?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--config",
+        config_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+    assert!(tmp_dir.path().join("build").is_dir());
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let contract = format!(
+        "{}:Callable",
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH
+    );
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        "--contract",
+        contract.as_str(),
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(contract.as_str()))
+        .stdout(predicate::str::contains("Main.sol:Main").not());
+
+    Ok(())
+}
+
+#[test]
+fn factory_dependencies() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let contract = format!(
+        "{}:Main",
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH
+    );
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        "--contract",
+        contract.as_str(),
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(contract.as_str()))
+        .stdout(predicate::str::contains("Callable.sol:Callable"));
+
+    Ok(())
+}
+
+#[test]
+fn not_found() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let contract = format!(
+        "{}:Missing",
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH
+    );
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        "--contract",
+        contract.as_str(),
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(format!(
+        "Contract `{contract}` is not found in the project."
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--contract",
+        "Default.yul:Default",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Contract selection is only supported in Solidity mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_MULTI_LEVEL_PATH,
+        "--deployment-plan",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("======= Deployment plan ======="))
+        .stdout(
+            predicate::str::is_match(
+                r#""libraries": \[\s*"[^"]*:LowerLibrary",\s*"[^"]*:UpperLibrary"\s*\]"#,
+            )
+            .expect("Always valid"),
+        );
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = tempfile::tempdir()?;
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_MULTI_LEVEL_PATH,
+        "--deployment-plan",
+        "--bin",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let deployment_plan: serde_json::Value = serde_json::from_str(
+        std::fs::read_to_string(
+            output_directory
+                .path()
+                .join(era_compiler_solidity::DeploymentPlan::FILE_NAME),
+        )?
+        .as_str(),
+    )?;
+    let upper_contract = &deployment_plan["contracts"][format!(
+        "{}:UpperContract",
+        crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_MULTI_LEVEL_PATH
+    )];
+    assert_eq!(
+        upper_contract["pendingLinkerSymbols"],
+        serde_json::json!([format!(
+            "{}:UpperLibrary",
+            crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_MULTI_LEVEL_PATH
+        )])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_DEPLOYMENT_PLAN_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let deployment_plan = &output["deploymentPlan"];
+    assert_eq!(
+        deployment_plan["libraries"],
+        serde_json::json!(["A:L1", "A:L2"])
+    );
+    assert_eq!(
+        deployment_plan["contracts"]["A:D"]["pendingLinkerSymbols"],
+        serde_json::json!(["A:L2"])
+    );
+    assert_eq!(
+        deployment_plan["contracts"]["A:D"]["factoryDependenciesUnlinked"],
+        serde_json::json!(["A:C"])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_mode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--deployment-plan",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Deployment plan is only supported in Solidity, Yul, LLVM IR, and EraVM assembly modes.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+        "--bin",
+        "--diagnostics-format",
+        "json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let stderr = result.success().get_output().stderr.clone();
+    let diagnostics = String::from_utf8(stderr)?
+        .lines()
+        .filter(|line| line.starts_with('{'))
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<serde_json::Value>, serde_json::Error>>()?;
+    assert!(diagnostics.iter().any(|diagnostic| {
+        diagnostic["severity"] == "warning"
+            && diagnostic["message"]
+                .as_str()
+                .is_some_and(|message| message.contains("tx.origin"))
+            && diagnostic["file"] == crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH
+            && diagnostic["line"].is_u64()
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn json_error() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        crate::common::TEST_LLVM_IR_CONTRACT_INVALID_PATH,
+        "--bin",
+        "--diagnostics-format",
+        "json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let stderr = result.failure().get_output().stderr.clone();
+    let diagnostics = String::from_utf8(stderr)?
+        .lines()
+        .filter(|line| line.starts_with('{'))
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<serde_json::Value>, serde_json::Error>>()?;
+    assert!(diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic["severity"] == "error"));
+
+    Ok(())
+}
+
+#[test]
+fn sarif() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+        "--bin",
+        "--diagnostics-format",
+        "sarif",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let stderr = result.success().get_output().stderr.clone();
+    let sarif = String::from_utf8(stderr)?
+        .lines()
+        .find(|line| line.starts_with('{'))
+        .map(serde_json::from_str::<serde_json::Value>)
+        .expect("SARIF log is missing")?;
+    assert_eq!(sarif["version"], "2.1.0");
+    let results = sarif["runs"][0]["results"]
+        .as_array()
+        .expect("SARIF results are missing");
+    assert!(results.iter().any(|result| {
+        result["level"] == "warning"
+            && result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+                == crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH
+            && result["locations"][0]["physicalLocation"]["region"]["startLine"].is_u64()
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--diagnostics-format",
+        "xml",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid diagnostics format"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--diagnostics-format",
+        "json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Diagnostics format is not supported in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -36,6 +36,91 @@ fn with_libraries() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn with_libraries_binary() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    std::fs::create_dir_all(crate::common::TEST_TEMP_DIRECTORY)?;
+    let binary_path = format!("{}/linker_binary.zbin", crate::common::TEST_TEMP_DIRECTORY);
+    let bytecode = std::fs::read_to_string(crate::common::TEST_LINKER_BYTECODE_PATH)?;
+    std::fs::write(binary_path.as_str(), hex::decode(bytecode.trim())?)?;
+
+    let args = &[
+        "--link",
+        binary_path.as_str(),
+        "--libraries",
+        crate::common::LIBRARY_LINKER,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(format!(
+        "\"linked\":{{\"{binary_path}\":"
+    )));
+
+    let linked = std::fs::read(binary_path.as_str())?;
+    assert_eq!(
+        era_compiler_solidity::LinkerFileFormat::detect(linked.as_slice()),
+        era_compiler_solidity::LinkerFileFormat::Binary
+    );
+    assert_eq!(linked.len() % era_compiler_common::BYTE_LENGTH_FIELD, 0);
+
+    std::fs::remove_file(binary_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn with_libraries_binary_output_format_hex() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    std::fs::create_dir_all(crate::common::TEST_TEMP_DIRECTORY)?;
+    let binary_path = format!(
+        "{}/linker_binary_to_hex.zbin",
+        crate::common::TEST_TEMP_DIRECTORY
+    );
+    let bytecode = std::fs::read_to_string(crate::common::TEST_LINKER_BYTECODE_PATH)?;
+    std::fs::write(binary_path.as_str(), hex::decode(bytecode.trim())?)?;
+
+    let args = &[
+        "--link",
+        binary_path.as_str(),
+        "--libraries",
+        crate::common::LIBRARY_LINKER,
+        "--link-output-format",
+        "hex",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let linked = std::fs::read_to_string(binary_path.as_str())?;
+    assert!(hex::decode(linked.as_str()).is_ok());
+
+    std::fs::remove_file(binary_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn link_output_format_standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--link",
+        "--standard-json",
+        crate::common::TEST_LINKER_STANDARD_JSON_INPUT_WITH_LIBRARIES_PATH,
+        "--link-output-format",
+        "binary",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Linker output format is only supported in linker mode with bytecode files.",
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn without_libraries() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -196,6 +281,170 @@ fn without_libraries_standard_json() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn standard_json_link_map() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--link",
+        "--standard-json",
+        crate::common::TEST_LINKER_STANDARD_JSON_INPUT_WITH_LIBRARIES_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        r#""linkMap":{"tests/data/bytecodes/linker.zbin":{"status":"linked","iteration":1,"#,
+    )).stdout(predicate::str::contains(
+        r#""linker_symbols":{"Greeter.sol:GreeterHelper":"0x1234567890abcdef1234567890abcdef12345678"}"#,
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn link_map_file() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    std::fs::create_dir_all(crate::common::TEST_TEMP_DIRECTORY)?;
+    let link_map_path = format!("{}/link_map.json", crate::common::TEST_TEMP_DIRECTORY);
+
+    let args = &[
+        "--link",
+        "--standard-json",
+        crate::common::TEST_LINKER_STANDARD_JSON_INPUT_WITHOUT_LIBRARIES_PATH,
+        "--link-map",
+        link_map_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let link_map: serde_json::Value =
+        serde_json::from_str(std::fs::read_to_string(link_map_path.as_str())?.as_str())?;
+    assert_eq!(
+        link_map["tests/data/bytecodes/linker.zbin"]["status"],
+        "unlinked"
+    );
+
+    std::fs::remove_file(link_map_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn link_map_not_linker_mode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--link-map",
+        "link_map.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Link map is only supported in linker mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn dependency_graph_file() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    std::fs::create_dir_all(crate::common::TEST_TEMP_DIRECTORY)?;
+    let dependency_graph_path = format!(
+        "{}/dependency_graph.dot",
+        crate::common::TEST_TEMP_DIRECTORY
+    );
+
+    let args = &[
+        "--link",
+        "--standard-json",
+        crate::common::TEST_LINKER_STANDARD_JSON_INPUT_WITH_LIBRARIES_PATH,
+        "--dependency-graph",
+        dependency_graph_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let dependency_graph = std::fs::read_to_string(dependency_graph_path.as_str())?;
+    assert!(dependency_graph.starts_with("digraph factory_dependencies {"));
+    assert!(dependency_graph.contains(r#""tests/data/bytecodes/linker.zbin" [style=solid];"#));
+
+    std::fs::remove_file(dependency_graph_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn dependency_graph_not_linker_mode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--dependency-graph",
+        "dependency_graph.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Dependency graph is only supported in linker mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_output() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    std::fs::create_dir_all(crate::common::TEST_TEMP_DIRECTORY)?;
+    let output_path = format!(
+        "{}/standard_json_output.json",
+        crate::common::TEST_TEMP_DIRECTORY
+    );
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+    let result = crate::cli::execute_zksolc(args)?;
+    let output = result.success().get_output().stdout.to_owned();
+    std::fs::write(output_path.as_str(), output)?;
+
+    let args = &["--link", "--standard-json-output", output_path.as_str()];
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""contracts":"#))
+        .stdout(predicate::str::contains(r#""objectFormat":"raw""#));
+
+    std::fs::remove_file(output_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_output_not_linker_mode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--standard-json-output",
+        "output.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Standard JSON output linking is only supported in linker mode.",
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn standard_json_missing() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -249,3 +498,54 @@ fn standard_json_linker_error() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn inspect() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--link",
+        "--inspect",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"GreeterHelper\":[{\"start\":"));
+
+    Ok(())
+}
+
+#[test]
+fn inspect_not_linker_mode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--inspect", crate::common::TEST_LINKER_BYTECODE_PATH];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Linker inspection is only supported in linker mode with bytecode files.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn library_create2_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--link",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        "--libraries-create2",
+        "Library.sol:Library=0x1234567890abcdef1234567890abcdef12345678:0x0000000000000000000000000000000000000000000000000000000000000001",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stdout(predicate::str::contains(
+        "CREATE2 library `Library.sol:Library` is not found among the linked input objects",
+    ));
+
+    Ok(())
+}
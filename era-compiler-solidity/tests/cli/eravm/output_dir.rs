@@ -75,3 +75,47 @@ fn eravm_assembly() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn yul_multiple_files() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_zksolc = TempDir::with_prefix("zksolc_output")?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_FACTORY_PATH,
+        crate::common::TEST_YUL_CONTRACT_DEPENDENCY_PATH,
+        "--yul",
+        "--bin",
+        "--output-dir",
+        tmp_dir_zksolc.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    for (input_path, object) in [
+        (crate::common::TEST_YUL_CONTRACT_FACTORY_PATH, "Factory"),
+        (
+            crate::common::TEST_YUL_CONTRACT_DEPENDENCY_PATH,
+            "Dependency",
+        ),
+    ] {
+        let input_path = PathBuf::from(input_path);
+        let input_file = input_path
+            .file_name()
+            .expect("Always exists")
+            .to_str()
+            .expect("Always valid");
+
+        let output_file = tmp_dir_zksolc.path().join(input_file).join(format!(
+            "{object}.{}",
+            era_compiler_common::EXTENSION_ERAVM_BINARY
+        ));
+        assert!(output_file.exists());
+    }
+
+    Ok(())
+}
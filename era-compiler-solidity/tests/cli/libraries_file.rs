@@ -0,0 +1,169 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn nested_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--libraries-file",
+        crate::common::TEST_LIBRARIES_FILE_NESTED_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    Ok(())
+}
+
+#[test]
+fn flat_toml() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--libraries-file",
+        crate::common::TEST_LIBRARIES_FILE_FLAT_TOML_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    Ok(())
+}
+
+#[test]
+fn checksum_invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--libraries-file",
+        crate::common::TEST_LIBRARIES_FILE_CHECKSUM_INVALID_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stderr(predicate::str::contains(
+        "Expected `0xF9702469Dfb84A9aC171E284F71615bd3D3f1EdC`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn collision() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--libraries-file",
+        crate::common::TEST_LIBRARIES_FILE_COLLISION_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stderr(predicate::str::contains(
+        "Address `0xf9702469dfb84a9ac171e284f71615bd3d3f1edc` is assigned to several libraries",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn linker() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    std::fs::create_dir_all(crate::common::TEST_TEMP_DIRECTORY)?;
+    let bytecode_path = format!(
+        "{}/linker_libraries_file.zbin",
+        crate::common::TEST_TEMP_DIRECTORY
+    );
+    std::fs::copy(
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        bytecode_path.as_str(),
+    )?;
+
+    let args = &[
+        "--link",
+        bytecode_path.as_str(),
+        "--libraries-file",
+        crate::common::TEST_LIBRARIES_FILE_LINKER_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(format!(
+        "\"linked\":{{\"{bytecode_path}\":"
+    )));
+
+    std::fs::remove_file(bytecode_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn linker_warnings() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--link",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        "--libraries-file",
+        crate::common::TEST_LIBRARIES_FILE_CHECKSUM_INVALID_PATH,
+        "--libraries",
+        "tests/data/contracts/solidity/Other.sol:Other=0xf9702469dfb84a9ac171e284f71615bd3d3f1edc",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stderr(
+        predicate::str::contains("Expected `0xF9702469Dfb84A9aC171E284F71615bd3D3f1EdC`.")
+            .and(predicate::str::contains(
+            "Address `0xf9702469dfb84a9ac171e284f71615bd3d3f1edc` is assigned to several libraries",
+        )),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--libraries-file",
+        crate::common::TEST_LIBRARIES_FILE_INVALID_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(
+        predicate::str::contains("Libraries file").and(predicate::str::contains("parsing")),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--libraries-file",
+        crate::common::TEST_LIBRARIES_FILE_NESTED_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Libraries must be passed via standard JSON input.",
+    ));
+
+    Ok(())
+}
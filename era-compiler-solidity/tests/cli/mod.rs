@@ -10,11 +10,17 @@ use assert_cmd::cargo::CommandCargoExt;
 
 mod allow_paths;
 mod asm;
+mod backend;
 mod base_path;
 mod bin;
+mod cache_dir;
 mod codegen;
 mod combined_json;
+mod config;
+mod contract;
 mod debug_output_dir;
+mod deployment_plan;
+mod diagnostics_format;
 mod disable_solc_optimizer;
 mod eravm;
 mod evm_version;
@@ -23,6 +29,7 @@ mod force_evmla;
 mod general;
 mod include_path;
 mod libraries;
+mod libraries_file;
 mod llvm_ir;
 mod llvm_options;
 mod metadata;
@@ -34,10 +41,14 @@ mod output_dir;
 mod overwrite;
 mod recursive_process;
 mod remappings;
+mod size_report;
 mod solc;
 mod standard_json;
+mod statistics;
 mod threads;
+mod verify;
 mod version;
+mod watch;
 mod yul;
 
 ///
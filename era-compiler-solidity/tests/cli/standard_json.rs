@@ -503,3 +503,57 @@ fn metadata_hash_none_no_metadata() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_IR_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let eravm = &output["contracts"]["A"]["C"]["eravm"];
+    assert!(eravm["llvmIr"]
+        .as_str()
+        .is_some_and(|llvm_ir| llvm_ir.contains("define")));
+    assert!(eravm["llvmIrOptimized"]
+        .as_str()
+        .is_some_and(|llvm_ir| llvm_ir.contains("define")));
+    assert!(eravm["yulOptimized"]
+        .as_str()
+        .is_some_and(|yul| yul.starts_with("object \"C_")));
+    assert!(eravm.get("assembly").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn statistics() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_STATISTICS_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let statistics = &output["contracts"]["A"]["C"]["eravm"]["statistics"];
+    assert!(statistics["functions"]
+        .as_u64()
+        .is_some_and(|count| count > 0));
+    assert!(statistics["instructions"]["control"]
+        .as_u64()
+        .is_some_and(|count| count > 0));
+    assert_eq!(statistics["sizeFallback"], serde_json::json!(false));
+    assert!(output["contracts"]["A"]["C"]["eravm"]
+        .get("assembly")
+        .is_none());
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--statistics"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("======= Statistics ======="))
+        .stderr(predicate::str::contains("Wall time: "))
+        .stderr(predicate::str::contains("Instructions: "));
+
+    Ok(())
+}
+
+#[test]
+fn eravm_assembly() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_ERAVM_ASSEMBLY_CONTRACT_PATH,
+        "--eravm-assembly",
+        "--statistics",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Functions: 1"))
+        .stderr(predicate::str::contains("Size fallback: no"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--statistics",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Compilation statistics are only supported in Solidity, Yul, LLVM IR, and EraVM assembly modes.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn cached() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_cache = TempDir::with_prefix("zksolc_cache")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--statistics",
+        "--cache-dir",
+        tmp_dir_cache.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("LLVM: "))
+        .stderr(predicate::str::contains(", cached").not());
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains(", cached"));
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+///
+/// Compiles the standard JSON input and writes the first contract bytecode to `path`.
+///
+fn write_bytecode(path: &str, is_binary: bool) -> anyhow::Result<()> {
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+    let result = crate::cli::execute_zksolc(args)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let bytecode = output["contracts"]
+        .as_object()
+        .and_then(|files| files.values().next())
+        .and_then(|file| file.as_object())
+        .and_then(|file| file.values().next())
+        .and_then(|contract| contract["eravm"]["bytecode"].as_str())
+        .expect("Missing bytecode");
+
+    std::fs::create_dir_all(crate::common::TEST_TEMP_DIRECTORY)?;
+    if is_binary {
+        std::fs::write(path, hex::decode(bytecode)?)?;
+    } else {
+        std::fs::write(path, bytecode)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let bytecode_path = format!("{}/verify.hex", crate::common::TEST_TEMP_DIRECTORY);
+    write_bytecode(bytecode_path.as_str(), false)?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--verify",
+        bytecode_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""verified":true"#))
+        .stdout(predicate::str::contains(r#""status":"exact""#));
+
+    std::fs::remove_file(bytecode_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn binary_ignore_metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let bytecode_path = format!("{}/verify.zbin", crate::common::TEST_TEMP_DIRECTORY);
+    write_bytecode(bytecode_path.as_str(), true)?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--verify",
+        bytecode_path.as_str(),
+        "--ignore-metadata",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""verified":true"#));
+
+    std::fs::remove_file(bytecode_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn mismatch() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    std::fs::create_dir_all(crate::common::TEST_TEMP_DIRECTORY)?;
+    let bytecode_path = format!("{}/verify_mismatch.hex", crate::common::TEST_TEMP_DIRECTORY);
+    std::fs::write(bytecode_path.as_str(), "00".repeat(96))?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--verify",
+        bytecode_path.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stdout(predicate::str::contains(r#""verified":false"#))
+        .stdout(predicate::str::contains(r#""status":"mismatch""#));
+
+    std::fs::remove_file(bytecode_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn not_standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--verify",
+        "bytecode.hex",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Bytecode verification is only supported in standard JSON mode.",
+    ));
+
+    Ok(())
+}
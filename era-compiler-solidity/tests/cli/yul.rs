@@ -40,6 +40,69 @@ fn solc() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn multiple_files() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_FACTORY_PATH,
+        crate::common::TEST_YUL_CONTRACT_DEPENDENCY_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "======= {}:Factory =======",
+            crate::common::TEST_YUL_CONTRACT_FACTORY_PATH
+        )))
+        .stdout(predicate::str::contains(format!(
+            "======= {}:Dependency =======",
+            crate::common::TEST_YUL_CONTRACT_DEPENDENCY_PATH
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn multiple_files_missing_dependency() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_FACTORY_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Factory dependency `Dependency` is not found among the project contracts.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn multiple_files_duplicate_object() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_DEPENDENCY_PATH,
+        crate::common::TEST_YUL_CONTRACT_DEPENDENCY_DUPLICATE_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Yul object `Dependency` is declared in both",
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn invalid_input() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -62,6 +62,10 @@ pub const TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_INTERFACE_EMPTY_YUL_PATH: &str =
     "tests/data/contracts/solidity/InterfaceEmptyYul.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH: &str =
+    "tests/data/contracts/solidity/TxOrigin.sol";
+
 /// A test input file.
 pub const SOLIDITY_BIN_OUTPUT_NAME_ERAVM: &str = "Test.zbin";
 
@@ -71,6 +75,17 @@ pub const SOLIDITY_ASM_OUTPUT_NAME_ERAVM: &str = "Test.zasm";
 /// A test input file.
 pub const TEST_YUL_CONTRACT_PATH: &str = "tests/data/contracts/yul/Default.yul";
 
+/// A test input file.
+pub const TEST_YUL_CONTRACT_FACTORY_PATH: &str = "tests/data/contracts/yul/multi_file/Factory.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_DEPENDENCY_PATH: &str =
+    "tests/data/contracts/yul/multi_file/Dependency.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_DEPENDENCY_DUPLICATE_PATH: &str =
+    "tests/data/contracts/yul/multi_file/DependencyDuplicate.yul";
+
 /// A test input file.
 pub const TEST_LLVM_IR_CONTRACT_ERAVM_PATH: &str = "tests/data/contracts/llvm_ir/Test.eravm.ll";
 
@@ -100,6 +115,18 @@ pub const TEST_SOLIDITY_STANDARD_JSON_INVALID_UTF8_PATH: &str =
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH: &str =
     "tests/data/standard_json_input/solidity_solc.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_DEPLOYMENT_PLAN_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_deployment_plan.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_IR_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_ir.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_STATISTICS_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_statistics.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_EMPTY_SOURCES_PATH: &str =
     "tests/data/standard_json_input/solidity_solc_empty_sources.json";
@@ -316,3 +343,22 @@ pub const LIBRARY_LINKER_ADDRESS_INVALID: &str = "Greeter.sol:GreeterHelper=XINV
 
 /// A test constant.
 pub const LIBRARY_LINKER_ADDRESS_INCORRECT_SIZE: &str = "Greeter.sol:GreeterHelper=0x12345678";
+
+/// A test input file.
+pub const TEST_LIBRARIES_FILE_NESTED_JSON_PATH: &str = "tests/data/libraries/nested.json";
+
+/// A test input file.
+pub const TEST_LIBRARIES_FILE_FLAT_TOML_PATH: &str = "tests/data/libraries/flat.toml";
+
+/// A test input file.
+pub const TEST_LIBRARIES_FILE_CHECKSUM_INVALID_PATH: &str =
+    "tests/data/libraries/checksum_invalid.json";
+
+/// A test input file.
+pub const TEST_LIBRARIES_FILE_COLLISION_PATH: &str = "tests/data/libraries/collision.json";
+
+/// A test input file.
+pub const TEST_LIBRARIES_FILE_LINKER_PATH: &str = "tests/data/libraries/linker.toml";
+
+/// A test input file.
+pub const TEST_LIBRARIES_FILE_INVALID_PATH: &str = "tests/data/libraries/invalid.json";
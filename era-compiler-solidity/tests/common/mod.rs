@@ -161,11 +161,18 @@ pub fn build_solidity_standard_json(
         optimizer_settings,
         vec![],
         false,
+        false,
+        false,
+        false,
+        false,
+        false,
+        None,
+        era_compiler_solidity::ProcessBackend::default(),
         None,
     )?;
     build.check_errors()?;
 
-    let build = build.link(linker_symbols);
+    let build = build.link(linker_symbols, false);
     build.check_errors()?;
 
     build.write_to_standard_json(&mut solc_output, Some(&solc_compiler.version))?;
@@ -219,6 +226,13 @@ pub fn build_solidity_combined_json(
         optimizer_settings,
         vec![],
         selectors.contains(&era_solc::CombinedJsonSelector::Assembly),
+        selectors.contains(&era_solc::CombinedJsonSelector::SourceMap),
+        false,
+        false,
+        false,
+        false,
+        None,
+        era_compiler_solidity::ProcessBackend::default(),
         None,
     )?;
     build.check_errors()?;
@@ -228,7 +242,7 @@ pub fn build_solidity_combined_json(
         selectors.into_iter().collect(),
         Some(solc_codegen),
     )?;
-    build.write_to_combined_json(&mut combined_json)?;
+    build.write_to_combined_json(&mut vec![], &mut combined_json, metadata_hash_type, false)?;
     Ok(combined_json)
 }
 
@@ -322,11 +336,18 @@ pub fn build_yul(
         optimizer_settings,
         vec![],
         false,
+        false,
+        false,
+        false,
+        false,
+        false,
+        None,
+        era_compiler_solidity::ProcessBackend::default(),
         None,
     )?;
     build.check_errors()?;
 
-    let build = build.link(BTreeMap::new());
+    let build = build.link(BTreeMap::new(), false);
     build.check_errors()?;
 
     build.write_to_standard_json(&mut solc_output, None)?;
@@ -378,11 +399,18 @@ pub fn build_yul_standard_json(
         optimizer_settings,
         vec![],
         false,
+        false,
+        false,
+        false,
+        false,
+        false,
+        None,
+        era_compiler_solidity::ProcessBackend::default(),
         None,
     )?;
     build.check_errors()?;
 
-    let build = build.link(BTreeMap::new());
+    let build = build.link(BTreeMap::new(), false);
     build.check_errors()?;
 
     build.write_to_standard_json(&mut solc_output, solc_version)?;
@@ -418,11 +446,18 @@ pub fn build_llvm_ir_standard_json(
         optimizer_settings,
         vec![],
         false,
+        false,
+        false,
+        false,
+        false,
+        false,
+        None,
+        era_compiler_solidity::ProcessBackend::default(),
         None,
     )?;
     build.check_errors()?;
 
-    let build = build.link(BTreeMap::new());
+    let build = build.link(BTreeMap::new(), false);
     build.check_errors()?;
 
     build.write_to_standard_json(&mut output, None)?;
@@ -454,11 +489,18 @@ pub fn build_eravm_assembly_standard_json(
         optimizer_settings,
         vec![],
         false,
+        false,
+        false,
+        false,
+        false,
+        false,
+        None,
+        era_compiler_solidity::ProcessBackend::default(),
         None,
     )?;
     build.check_errors()?;
 
-    let build = build.link(BTreeMap::new());
+    let build = build.link(BTreeMap::new(), false);
     build.check_errors()?;
 
     build.write_to_standard_json(&mut output, None)?;
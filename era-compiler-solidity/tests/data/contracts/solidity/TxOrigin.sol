@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.4.16;
+
+contract TxOrigin {
+    function isOrigin() public view returns (bool) {
+        return msg.sender == tx.origin;
+    }
+}
@@ -2,6 +2,8 @@
 //! Unit tests for combined JSON.
 //!
 
+use test_case::test_case;
+
 #[test]
 fn one_file() {
     let paths = [crate::common::TEST_SOLIDITY_CONTRACT_PATH];
@@ -203,3 +205,102 @@ fn eravm_assembly_not_requested() {
             .is_none());
     }
 }
+
+#[test]
+fn eravm_source_map_requested() {
+    let paths = [crate::common::TEST_SOLIDITY_CONTRACT_PATH];
+    let names = ["Test"];
+    let sources = crate::common::read_sources(paths.as_slice());
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)
+            .expect("`solc` initialization error");
+
+    let combined_json = crate::common::build_solidity_combined_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        vec![era_solc::CombinedJsonSelector::SourceMap],
+        era_compiler_common::MetadataHashType::IPFS,
+        &solc_compiler.version.default,
+        era_solc::StandardJsonInputCodegen::Yul,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    assert_eq!(combined_json.contracts.len(), paths.len());
+    for (path, name) in paths.into_iter().zip(names.into_iter()) {
+        let full_path = format!("{path}:{name}");
+        let contract = combined_json
+            .contracts
+            .get(full_path.as_str())
+            .unwrap_or_else(|| panic!("The contract `{full_path}` is missing"));
+        assert!(!contract
+            .srcmap
+            .as_ref()
+            .expect("The `srcmap` field is missing")
+            .is_empty());
+        assert!(
+            contract.assembly.is_none(),
+            "The `assembly` field is present"
+        );
+    }
+}
+
+#[test_case(era_solc::StandardJsonInputCodegen::EVMLA)]
+#[test_case(era_solc::StandardJsonInputCodegen::Yul)]
+fn eravm_source_map_locations(codegen: era_solc::StandardJsonInputCodegen) {
+    let path = crate::common::TEST_SOLIDITY_CONTRACT_PATH;
+    let sources = crate::common::read_sources(&[path]);
+    let source_length = std::fs::read_to_string(path).expect("Test failure").len() as isize;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)
+            .expect("`solc` initialization error");
+
+    let combined_json = crate::common::build_solidity_combined_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        vec![
+            era_solc::CombinedJsonSelector::SourceMap,
+            era_solc::CombinedJsonSelector::Assembly,
+        ],
+        era_compiler_common::MetadataHashType::IPFS,
+        &solc_compiler.version.default,
+        codegen,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let contract = combined_json
+        .contracts
+        .get(format!("{path}:Test").as_str())
+        .expect("The contract is missing");
+    assert!(
+        contract
+            .assembly
+            .as_ref()
+            .expect("The `assembly` field is missing")
+            .contains(".loc"),
+        "The debug locations have not reached the assembly"
+    );
+
+    let mut entry = [-1isize; 3];
+    let mut is_mapped = false;
+    for compressed in contract
+        .srcmap
+        .as_ref()
+        .expect("The `srcmap` field is missing")
+        .split(';')
+    {
+        for (index, value) in compressed.split(':').enumerate() {
+            if !value.is_empty() {
+                entry[index] = value.parse().expect("Invalid source map entry");
+            }
+        }
+        let [begin, length, source] = entry;
+        if source == 0 && begin >= 0 && length > 0 && begin + length <= source_length {
+            is_mapped = true;
+        }
+    }
+    assert!(is_mapped, "No instruction is mapped to the source code");
+}
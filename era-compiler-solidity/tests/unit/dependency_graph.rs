@@ -0,0 +1,92 @@
+//!
+//! Unit tests for the factory dependency graph.
+//!
+
+use std::collections::BTreeSet;
+
+fn dependencies(paths: &[&str]) -> BTreeSet<String> {
+    paths.iter().map(|path| path.to_string()).collect()
+}
+
+#[test]
+fn cycle() {
+    let mut graph = era_compiler_solidity::DependencyGraph::default();
+    graph.add("B".to_owned(), false, dependencies(&["A"]));
+    graph.add("A".to_owned(), false, dependencies(&["B"]));
+    graph.add("C".to_owned(), false, dependencies(&["A"]));
+
+    assert_eq!(
+        graph.cycles(),
+        vec![vec!["A".to_owned(), "B".to_owned(), "A".to_owned()]]
+    );
+    let errors = graph.errors();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0]
+        .message
+        .contains("Factory dependency cycle: `A` → `B` → `A`."));
+}
+
+#[test]
+fn self_cycle() {
+    let mut graph = era_compiler_solidity::DependencyGraph::default();
+    graph.add("A".to_owned(), false, dependencies(&["A"]));
+
+    assert_eq!(graph.cycles(), vec![vec!["A".to_owned(), "A".to_owned()]]);
+}
+
+#[test]
+fn missing() {
+    let mut graph = era_compiler_solidity::DependencyGraph::default();
+    graph.add("A".to_owned(), false, dependencies(&["B"]));
+    graph.add("B".to_owned(), false, dependencies(&["C"]));
+    graph.add("D".to_owned(), true, dependencies(&[]));
+
+    assert!(graph.cycles().is_empty());
+    let errors = graph.errors();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.contains(
+        "Factory dependency `C` is not found among the input objects. It prevents linking of: `A`, `B`."
+    ));
+}
+
+#[test]
+fn linked() {
+    let mut graph = era_compiler_solidity::DependencyGraph::default();
+    graph.add("A".to_owned(), true, dependencies(&["B"]));
+    graph.add("B".to_owned(), true, dependencies(&[]));
+
+    assert!(graph.errors().is_empty());
+}
+
+#[test]
+fn dot() {
+    let mut graph = era_compiler_solidity::DependencyGraph::default();
+    graph.add("A".to_owned(), false, dependencies(&["B"]));
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph factory_dependencies {"));
+    assert!(dot.contains("\"A\" [style=bold];"));
+    assert!(dot.contains("\"B\" [style=dashed];"));
+    assert!(dot.contains("\"A\" -> \"B\";"));
+}
+
+#[test]
+fn cycles_shared_object() {
+    let mut graph = era_compiler_solidity::DependencyGraph::default();
+    graph.add("A".to_owned(), false, dependencies(&["B", "C"]));
+    graph.add("B".to_owned(), false, dependencies(&["C"]));
+    graph.add("C".to_owned(), false, dependencies(&["A"]));
+
+    assert_eq!(
+        graph.cycles(),
+        vec![
+            vec![
+                "A".to_owned(),
+                "B".to_owned(),
+                "C".to_owned(),
+                "A".to_owned()
+            ],
+            vec!["A".to_owned(), "C".to_owned(), "A".to_owned()],
+        ]
+    );
+}
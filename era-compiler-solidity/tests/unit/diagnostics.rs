@@ -0,0 +1,42 @@
+//!
+//! Unit tests for the CLI diagnostics.
+//!
+
+const SOURCE_CODE: &str = "contract Test {\n    uint256 value;\n}\n";
+
+const PATH: &str = "Test.sol";
+
+fn diagnostic(start: isize, end: isize) -> era_compiler_solidity::Diagnostic {
+    let error = era_solc::StandardJsonOutputError::new_warning(
+        "Test warning",
+        Some(
+            era_solc::StandardJsonOutputErrorSourceLocation::new_with_offsets(
+                PATH.to_owned(),
+                start,
+                end,
+            ),
+        ),
+        None,
+    );
+    era_compiler_solidity::Diagnostic::new(&error, Some(SOURCE_CODE))
+}
+
+#[test]
+fn column() {
+    let diagnostic = diagnostic(20, 27);
+
+    assert_eq!(diagnostic.line, Some(2));
+    assert_eq!(diagnostic.column, Some(5));
+
+    let region = &diagnostic.to_sarif_result()["locations"][0]["physicalLocation"]["region"];
+    assert_eq!(region["startLine"], 2);
+    assert_eq!(region["startColumn"], 5);
+}
+
+#[test]
+fn column_start_of_file() {
+    let diagnostic = diagnostic(0, 8);
+
+    assert_eq!(diagnostic.line, Some(1));
+    assert_eq!(diagnostic.column, Some(1));
+}
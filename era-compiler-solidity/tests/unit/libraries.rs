@@ -2,6 +2,7 @@
 //! Unit tests for libraries.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use test_case::test_case;
@@ -122,3 +123,94 @@ fn specified(version: semver::Version, codegen: era_solc::StandardJsonInputCodeg
         "The list of missing libraries must be empty"
     );
 }
+
+#[test]
+fn missing_details_transitive() {
+    let location =
+        era_solc::StandardJsonOutputContractMissingLibraryLocation::Yul { line: 1, column: 1 };
+    let second_location =
+        era_solc::StandardJsonOutputContractMissingLibraryLocation::Yul { line: 2, column: 5 };
+    let library_location = era_solc::StandardJsonOutputContractMissingLibraryLocation::EVMLA {
+        source: Some(0),
+        begin: 10,
+        end: 20,
+    };
+
+    let mut references = BTreeMap::new();
+    references.insert(
+        "Main.sol:Main".to_owned(),
+        BTreeMap::from([(
+            "Upper.sol:Upper".to_owned(),
+            vec![location, second_location],
+        )]),
+    );
+    references.insert(
+        "Upper.sol:Upper".to_owned(),
+        BTreeMap::from([("Lower.sol:Lower".to_owned(), vec![library_location])]),
+    );
+    references.insert(
+        "Lower.sol:Lower".to_owned(),
+        BTreeMap::from([("Upper.sol:Upper".to_owned(), vec![location])]),
+    );
+
+    let missing_libraries =
+        era_compiler_solidity::MissingLibraries::new(references, &BTreeSet::new());
+
+    assert_eq!(
+        missing_libraries.contract_libraries.get("Main.sol:Main"),
+        Some(&BTreeSet::from(["Upper.sol:Upper".to_owned()]))
+    );
+
+    let details = missing_libraries
+        .contract_details
+        .get("Main.sol:Main")
+        .expect("Always exists");
+    assert_eq!(
+        details.get("Upper.sol:Upper"),
+        Some(&era_solc::StandardJsonOutputContractMissingLibrary::new(
+            "Main.sol:Main".to_owned(),
+            vec![location, second_location],
+            vec!["Upper.sol:Upper".to_owned()],
+        ))
+    );
+    assert_eq!(
+        details.get("Lower.sol:Lower"),
+        Some(&era_solc::StandardJsonOutputContractMissingLibrary::new(
+            "Upper.sol:Upper".to_owned(),
+            vec![library_location],
+            vec!["Upper.sol:Upper".to_owned(), "Lower.sol:Lower".to_owned()],
+        ))
+    );
+    assert_eq!(details.len(), 2);
+}
+
+#[test]
+fn missing_details_transitive_deployed() {
+    let location =
+        era_solc::StandardJsonOutputContractMissingLibraryLocation::Yul { line: 1, column: 1 };
+
+    let mut references = BTreeMap::new();
+    references.insert(
+        "Main.sol:Main".to_owned(),
+        BTreeMap::from([("Upper.sol:Upper".to_owned(), vec![location])]),
+    );
+    references.insert(
+        "Upper.sol:Upper".to_owned(),
+        BTreeMap::from([("Lower.sol:Lower".to_owned(), vec![location])]),
+    );
+
+    let deployed_libraries = BTreeSet::from(["Lower.sol:Lower".to_owned()]);
+    let missing_libraries =
+        era_compiler_solidity::MissingLibraries::new(references, &deployed_libraries);
+
+    let details = missing_libraries
+        .contract_details
+        .get("Main.sol:Main")
+        .expect("Always exists");
+    assert!(details.contains_key("Upper.sol:Upper"));
+    assert!(!details.contains_key("Lower.sol:Lower"));
+    assert_eq!(
+        missing_libraries.contract_libraries.get("Upper.sol:Upper"),
+        Some(&BTreeSet::new())
+    );
+}
@@ -0,0 +1,98 @@
+//!
+//! Unit tests for the libraries file.
+//!
+
+use std::path::Path;
+
+#[test]
+fn nested_json() {
+    let libraries_file = era_compiler_solidity::LibrariesFile::try_from_path(Path::new(
+        crate::common::TEST_LIBRARIES_FILE_NESTED_JSON_PATH,
+    ))
+    .expect("Test failure");
+
+    assert_eq!(
+        libraries_file.to_arguments(),
+        vec![crate::common::LIBRARY_DEFAULT.to_owned()]
+    );
+    assert!(libraries_file.warnings(&[]).is_empty());
+}
+
+#[test]
+fn flat_toml() {
+    let libraries_file = era_compiler_solidity::LibrariesFile::try_from_path(Path::new(
+        crate::common::TEST_LIBRARIES_FILE_FLAT_TOML_PATH,
+    ))
+    .expect("Test failure");
+
+    assert_eq!(
+        libraries_file.to_arguments(),
+        vec![crate::common::LIBRARY_DEFAULT.to_owned()]
+    );
+    assert!(libraries_file.warnings(&[]).is_empty());
+}
+
+#[test]
+fn checksum_invalid() {
+    let libraries_file = era_compiler_solidity::LibrariesFile::try_from_path(Path::new(
+        crate::common::TEST_LIBRARIES_FILE_CHECKSUM_INVALID_PATH,
+    ))
+    .expect("Test failure");
+
+    let warnings = libraries_file.warnings(&[]);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0]
+        .message
+        .contains("does not match its EIP-55 checksum"));
+}
+
+#[test]
+fn collision() {
+    let libraries_file = era_compiler_solidity::LibrariesFile::try_from_path(Path::new(
+        crate::common::TEST_LIBRARIES_FILE_COLLISION_PATH,
+    ))
+    .expect("Test failure");
+
+    let warnings = libraries_file.warnings(&[]);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].message.contains(
+        "`tests/data/contracts/solidity/MiniMath.sol:MiniMath`, `tests/data/contracts/solidity/Other.sol:Other`"
+    ));
+}
+
+#[test]
+fn invalid() {
+    let result = era_compiler_solidity::LibrariesFile::try_from_path(Path::new(
+        crate::common::TEST_LIBRARIES_FILE_INVALID_PATH,
+    ));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn collision_inline() {
+    let libraries_file = era_compiler_solidity::LibrariesFile::try_from_path(Path::new(
+        crate::common::TEST_LIBRARIES_FILE_CHECKSUM_INVALID_PATH,
+    ))
+    .expect("Test failure");
+
+    let warnings = libraries_file.warnings(&[
+        "tests/data/contracts/solidity/Other.sol:Other=0xf9702469dfb84a9ac171e284f71615bd3d3f1edc"
+            .to_owned(),
+    ]);
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[1].message.contains(
+        "`tests/data/contracts/solidity/MiniMath.sol:MiniMath`, `tests/data/contracts/solidity/Other.sol:Other`"
+    ));
+}
+
+#[test]
+fn collision_inline_same_library() {
+    let libraries_file = era_compiler_solidity::LibrariesFile::try_from_path(Path::new(
+        crate::common::TEST_LIBRARIES_FILE_NESTED_JSON_PATH,
+    ))
+    .expect("Test failure");
+
+    let warnings = libraries_file.warnings(&[crate::common::LIBRARY_DEFAULT.to_owned()]);
+    assert!(warnings.is_empty());
+}
@@ -132,7 +132,7 @@ fn library_not_passed_post_compile_time(
     let mut bytecodes = BTreeMap::new();
     bytecodes.insert(full_path.clone(), hex::encode(bytecode));
 
-    let input = era_compiler_solidity::LinkerInput::new(bytecodes, vec![]);
+    let input = era_compiler_solidity::LinkerInput::new(bytecodes, vec![], vec![]);
     let output = era_compiler_solidity::Linker::link_eravm(input).expect("Linker failed");
     assert!(
         output.unlinked.contains_key(full_path.as_str()),
@@ -234,7 +234,7 @@ fn library_passed_post_compile_time(
     let mut bytecodes = BTreeMap::new();
     bytecodes.insert(full_path.clone(), hex::encode(bytecode));
 
-    let input = era_compiler_solidity::LinkerInput::new(bytecodes, libraries);
+    let input = era_compiler_solidity::LinkerInput::new(bytecodes, libraries, vec![]);
     let output = era_compiler_solidity::Linker::link_eravm(input).expect("Linker failed");
     assert!(
         output.linked.contains_key(full_path.as_str()),
@@ -350,7 +350,7 @@ fn library_passed_post_compile_time_redundant_args(
     let mut bytecodes = BTreeMap::new();
     bytecodes.insert(full_path.clone(), hex::encode(bytecode));
 
-    let input = era_compiler_solidity::LinkerInput::new(bytecodes, libraries);
+    let input = era_compiler_solidity::LinkerInput::new(bytecodes, libraries, vec![]);
     let output = era_compiler_solidity::Linker::link_eravm(input).expect("Linker failed");
     assert!(
         output.linked.contains_key(full_path.as_str()),
@@ -553,7 +553,7 @@ fn libraries_passed_post_compile_time_complex(
         .flatten()
         .collect::<BTreeMap<String, String>>();
 
-    let input = era_compiler_solidity::LinkerInput::new(bytecodes, libraries);
+    let input = era_compiler_solidity::LinkerInput::new(bytecodes, libraries, vec![]);
     let output = era_compiler_solidity::Linker::link_eravm(input).expect("Linker failed");
     assert!(!output.linked.is_empty(), "No linked objects found");
     assert!(
@@ -620,7 +620,7 @@ fn libraries_not_passed_post_compile_time_complex(
         .flatten()
         .collect::<BTreeMap<String, String>>();
 
-    let input = era_compiler_solidity::LinkerInput::new(bytecodes, vec![]);
+    let input = era_compiler_solidity::LinkerInput::new(bytecodes, vec![], vec![]);
     let output = era_compiler_solidity::Linker::link_eravm(input).expect("Linker failed");
     assert!(
         !output.ignored.is_empty(),
@@ -628,3 +628,451 @@ fn libraries_not_passed_post_compile_time_complex(
     );
     assert!(!output.unlinked.is_empty(), "No unlinked objects found");
 }
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn factory_dependency_missing(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    let path = crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH;
+    let sources = crate::common::read_sources(&[path]);
+
+    let build = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::MetadataHashType::None,
+        BTreeSet::new(),
+        &version,
+        codegen,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let bytecodes = build
+        .contracts
+        .get(path)
+        .expect("Missing file")
+        .iter()
+        .filter(|(name, _contract)| name.as_str() != "InnerContract")
+        .map(|(name, contract)| {
+            let bytecode = contract
+                .evm
+                .as_ref()
+                .expect("Missing EVM object")
+                .bytecode
+                .as_ref()
+                .expect("Missing bytecode")
+                .object
+                .to_owned();
+            (format!("{path}:{name}"), bytecode)
+        })
+        .collect::<BTreeMap<String, String>>();
+
+    let input = era_compiler_solidity::LinkerInput::new(bytecodes, vec![], vec![]);
+    let output = era_compiler_solidity::Linker::link_eravm(input).expect("Linker failed");
+    assert!(
+        output
+            .unlinked
+            .contains_key(format!("{path}:UpperLibrary").as_str()),
+        "The dependent object was linked"
+    );
+    assert!(
+        output.errors.iter().any(|error| error
+            .message
+            .contains(format!("Factory dependency `{path}:InnerContract` is not found").as_str())),
+        "The missing factory dependency is not reported"
+    );
+}
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn standard_json_output(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    let path = crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH;
+    let sources = crate::common::read_sources(&[path]);
+
+    let build = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::MetadataHashType::None,
+        BTreeSet::new(),
+        &version,
+        codegen,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let upper_contract = &build.contracts[path]["UpperContract"];
+    assert_eq!(
+        upper_contract.object_format,
+        Some(era_compiler_common::ObjectFormat::ELF)
+    );
+    assert!(upper_contract.hash.is_none());
+    let output_json = serde_json::to_string(&build).expect("Always valid");
+
+    let libraries = vec![format!(
+        "{path}:UpperLibrary=0x1234567890abcdef1234567890abcdef12345678"
+    )];
+    let (standard_json, output) = era_compiler_solidity::Linker::link_eravm_standard_json(
+        output_json.as_str(),
+        libraries,
+        vec![],
+    )
+    .expect("Linker failed");
+    assert!(output.unlinked.is_empty(), "Unlinked objects found");
+
+    let upper_contract = &standard_json.contracts[path]["UpperContract"];
+    assert_eq!(
+        upper_contract.object_format,
+        Some(era_compiler_common::ObjectFormat::Raw)
+    );
+    assert!(
+        upper_contract.hash.is_some(),
+        "The bytecode hash is missing"
+    );
+    assert!(upper_contract.missing_libraries.is_empty());
+
+    let upper_library = &standard_json.contracts[path]["UpperLibrary"];
+    assert!(
+        upper_library
+            .factory_dependencies
+            .values()
+            .any(|dependency| dependency == format!("{path}:InnerContract").as_str()),
+        "The resolved factory dependency is missing"
+    );
+}
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn standard_json_output_eravm_preserved(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    let path = crate::common::TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH;
+    let sources = crate::common::read_sources(&[path]);
+
+    let mut build = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::MetadataHashType::None,
+        BTreeSet::new(),
+        &version,
+        codegen,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let eravm = build
+        .contracts
+        .get_mut(path)
+        .and_then(|file| file.get_mut("UpperContract"))
+        .and_then(|contract| contract.eravm.as_mut())
+        .expect("Missing EraVM object");
+    eravm.sizes.size_fallback = true;
+    eravm.source_map = Some("0:1:0".to_owned());
+    let unlinked_bytecode = eravm.bytecode.clone();
+    let output_json = serde_json::to_string(&build).expect("Always valid");
+
+    let libraries = vec![format!(
+        "{path}:UpperLibrary=0x1234567890abcdef1234567890abcdef12345678"
+    )];
+    let (standard_json, _output) = era_compiler_solidity::Linker::link_eravm_standard_json(
+        output_json.as_str(),
+        libraries,
+        vec![],
+    )
+    .expect("Linker failed");
+
+    let eravm = standard_json.contracts[path]["UpperContract"]
+        .eravm
+        .as_ref()
+        .expect("Missing EraVM object");
+    assert_ne!(eravm.bytecode, unlinked_bytecode);
+    assert_eq!(eravm.sizes.bytes * 2, eravm.bytecode.len());
+    assert!(eravm.sizes.size_fallback, "The size fallback flag is lost");
+    assert_eq!(eravm.source_map.as_deref(), Some("0:1:0"));
+}
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn library_create2(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    let library_path = format!(
+        "{}:SimpleLibrary",
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+    );
+    let contract_path = format!(
+        "{}:SimpleContract",
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+    );
+    let libraries_create2 = vec![format!(
+        "{library_path}=0x1234567890abcdef1234567890abcdef12345678:0x0000000000000000000000000000000000000000000000000000000000000001"
+    )];
+
+    let mut bytecodes = BTreeMap::new();
+    for name in ["SimpleContract", "SimpleLibrary"] {
+        let bytecode = get_bytecode(
+            crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
+            name,
+            era_compiler_common::Libraries::default(),
+            &version,
+            codegen,
+        );
+        bytecodes.insert(
+            format!(
+                "{}:{name}",
+                crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+            ),
+            hex::encode(bytecode),
+        );
+    }
+
+    let input = era_compiler_solidity::LinkerInput::new(bytecodes, vec![], libraries_create2);
+    let output = era_compiler_solidity::Linker::link_eravm(input).expect("Linker failed");
+    assert!(
+        output.errors.is_empty(),
+        "Linker errors: {:?}",
+        output.errors
+    );
+    assert!(
+        output.linked.contains_key(contract_path.as_str()),
+        "The bytecode is not linked"
+    );
+
+    let library_hash: [u8; era_compiler_common::BYTE_LENGTH_FIELD] = hex::decode(
+        output
+            .ignored
+            .get(library_path.as_str())
+            .expect("Always exists")
+            .hash
+            .as_str(),
+    )
+    .expect("Always valid")
+    .try_into()
+    .expect("Always valid");
+    let create2 = era_compiler_solidity::LinkerCreate2::try_from_specifiers(&[format!(
+        "{library_path}=0x1234567890abcdef1234567890abcdef12345678:0x0000000000000000000000000000000000000000000000000000000000000001"
+    )])
+    .expect("Always valid")
+    .remove(library_path.as_str())
+    .expect("Always exists");
+    assert_eq!(
+        output
+            .link_map
+            .get(contract_path.as_str())
+            .expect("Always exists")
+            .linker_symbols
+            .get(library_path.as_str()),
+        Some(&format!(
+            "0x{}",
+            hex::encode(create2.address(&library_hash))
+        ))
+    );
+}
+
+#[test]
+fn library_create2_missing() {
+    let bytecodes = BTreeMap::new();
+    let libraries_create2 = vec![
+        "Library.sol:Library=0x1234567890abcdef1234567890abcdef12345678:0x0000000000000000000000000000000000000000000000000000000000000001".to_owned(),
+    ];
+
+    let input = era_compiler_solidity::LinkerInput::new(bytecodes, vec![], libraries_create2);
+    let output = era_compiler_solidity::Linker::link_eravm(input).expect("Linker failed");
+    assert!(output.errors.iter().any(|error| error
+        .message
+        .contains("CREATE2 library `Library.sol:Library` is not found")));
+}
+
+#[test]
+fn create2_address() {
+    let create2 = era_compiler_solidity::LinkerCreate2::try_from_specifiers(&[
+        "Library.sol:Library=0x1234567890abcdef1234567890abcdef12345678:0x0000000000000000000000000000000000000000000000000000000000000001".to_owned(),
+    ])
+    .expect("Always valid")
+    .remove("Library.sol:Library")
+    .expect("Always exists");
+    let bytecode_hash: [u8; era_compiler_common::BYTE_LENGTH_FIELD] =
+        hex::decode("010000d5bf4dd6262304eb67a95a76e6e4b0e9f1dc3d2c524c129c6464939407")
+            .expect("Always valid")
+            .try_into()
+            .expect("Always valid");
+
+    assert_eq!(
+        hex::encode(create2.address(&bytecode_hash)),
+        "72b814338ca07c03fc5b4f1be6b625880c93fc2e"
+    );
+}
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn inspect(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    let address = "0x1234567890abcdef1234567890abcdef12345678";
+    let library_arguments = vec![format!(
+        "{}:SimpleLibrary={address}",
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+    )];
+    let linker_symbols = era_compiler_common::Libraries::try_from(library_arguments.as_slice())
+        .expect("Always valid")
+        .as_linker_symbols()
+        .expect("Always valid");
+
+    let bytecode = get_bytecode(
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
+        "SimpleContract",
+        era_compiler_common::Libraries::default(),
+        &version,
+        codegen,
+    );
+    let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
+        bytecode.as_slice(),
+        "bytecode",
+        false,
+    );
+    let linked = memory_buffer
+        .link_eravm(&linker_symbols, &BTreeMap::new())
+        .expect("Link failure");
+
+    let inspection = era_compiler_solidity::LinkerInspection::try_new(
+        "bytecode",
+        bytecode.as_slice(),
+        Some(linked.as_slice()),
+    )
+    .expect("Inspection failure");
+    let references = inspection
+        .link_references
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .and_then(|file| file.get("SimpleLibrary"))
+        .expect("Always exists");
+    assert!(!references.is_empty(), "No references found");
+    for reference in references.iter() {
+        assert_eq!(
+            reference.length,
+            era_compiler_common::BYTE_LENGTH_ETH_ADDRESS
+        );
+        assert_eq!(
+            &linked.as_slice()[reference.start..reference.start + reference.length],
+            hex::decode(&address[2..]).expect("Always valid").as_slice()
+        );
+    }
+    assert_eq!(
+        inspection.linker_symbols.get(
+            format!(
+                "{}:SimpleLibrary",
+                crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+            )
+            .as_str()
+        ),
+        Some(&address.to_owned())
+    );
+    assert_eq!(inspection.is_matching, Some(true));
+}
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn standard_json_link_references(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let mut build = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::MetadataHashType::None,
+        BTreeSet::new(),
+        &version,
+        codegen,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let eravm = build.contracts[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]
+        ["SimpleContract"]
+        .eravm
+        .as_ref()
+        .expect("Missing EraVM data");
+    assert!(
+        eravm.link_references.is_none(),
+        "References are not selected"
+    );
+    assert!(
+        eravm.bytecode_template.is_none(),
+        "References are not selected"
+    );
+
+    era_compiler_solidity::LinkerInspection::write_to_standard_json(&mut build, true, false);
+    assert!(
+        !build
+            .errors
+            .iter()
+            .any(|error| error.to_string().contains("references inspection")),
+        "Inspection failure"
+    );
+    let eravm = build.contracts[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]
+        ["SimpleContract"]
+        .eravm
+        .as_ref()
+        .expect("Missing EraVM data");
+    assert!(eravm.factory_dependency_references.is_none());
+
+    let references = eravm
+        .link_references
+        .as_ref()
+        .and_then(|references| {
+            references.get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        })
+        .and_then(|file| file.get("SimpleLibrary"))
+        .expect("Always exists");
+    assert!(!references.is_empty(), "No references found");
+    let template = hex::decode(
+        eravm
+            .bytecode_template
+            .as_deref()
+            .expect("Missing template"),
+    )
+    .expect("Always valid");
+    for reference in references.iter() {
+        assert_eq!(
+            reference.length,
+            era_compiler_common::BYTE_LENGTH_ETH_ADDRESS
+        );
+        assert!(
+            template[reference.start..reference.start + reference.length]
+                .iter()
+                .all(|byte| *byte == 0)
+        );
+    }
+}
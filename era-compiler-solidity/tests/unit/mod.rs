@@ -3,12 +3,21 @@
 //!
 
 mod combined_json;
+mod dependency_graph;
+mod diagnostics;
 mod factory_dependency;
 mod ir_artifacts;
 mod libraries;
+mod libraries_file;
 mod linker;
 mod messages;
 mod optimizer;
 mod remappings;
+mod session;
+mod size;
+mod source_map;
 mod standard_json;
+mod statistics;
 mod unsupported_instructions;
+mod verifier;
+mod watch;
@@ -0,0 +1,92 @@
+//!
+//! Unit tests for the compilation session API.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use era_solc::CollectableError;
+
+#[test]
+fn solidity() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?;
+    let mut session = era_compiler_solidity::CompilerBuilder::new()
+        .solc(solc_compiler.executable)
+        .optimizer_settings(era_compiler_llvm_context::OptimizerSettings::none())
+        .build()?;
+
+    let build = session
+        .compile_solidity(&[PathBuf::from(crate::common::TEST_SOLIDITY_CONTRACT_PATH)])
+        .expect("Compilation failure");
+    assert!(!build.results.is_empty());
+    assert!(build.results.values().all(|result| result.is_ok()));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut session = era_compiler_solidity::CompilerBuilder::new()
+        .output_assembly(true)
+        .build()?;
+
+    let build = session
+        .compile_yul(&[PathBuf::from(crate::common::TEST_YUL_CONTRACT_PATH)])
+        .expect("Compilation failure");
+    for result in build.results.into_values() {
+        let contract = result.expect("Compilation failure");
+        assert!(contract.build.assembly.is_some());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn yul_structured_errors() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut session = era_compiler_solidity::CompilerBuilder::new().build()?;
+
+    let errors = session
+        .compile_yul(&[PathBuf::from(crate::common::TEST_YUL_CONTRACT_FACTORY_PATH)])
+        .expect_err("Compilation must fail");
+    assert!(errors.iter().any(|error| error.severity == "error"
+        && error
+            .message
+            .contains("is not found among the project contracts")
+        && error.source_location.is_some()));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_errors_do_not_exit() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?;
+    let mut session = era_compiler_solidity::CompilerBuilder::new()
+        .solc(solc_compiler.executable)
+        .build()?;
+
+    let solc_input = era_solc::StandardJsonInput::try_from(Some(Path::new(
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_INVALID_PATH,
+    )))?;
+    let solc_output = session.standard_json(solc_input);
+    assert!(solc_output.has_errors());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_libraries() {
+    let result = era_compiler_solidity::CompilerBuilder::new()
+        .libraries(vec!["Library.sol:Library".to_owned()])
+        .build();
+    assert!(result.is_err());
+}
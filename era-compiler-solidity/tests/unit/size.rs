@@ -0,0 +1,109 @@
+//!
+//! Unit tests for the EraVM bytecode size checks.
+//!
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use era_solc::CollectableError;
+
+fn get_contract() -> anyhow::Result<era_compiler_solidity::EraVMContractBuild> {
+    crate::common::setup()?;
+
+    let mut session = era_compiler_solidity::CompilerBuilder::new().build()?;
+    let build = session
+        .compile_yul(&[PathBuf::from(crate::common::TEST_YUL_CONTRACT_PATH)])
+        .expect("Compilation failure");
+    let contract = build
+        .results
+        .into_values()
+        .next()
+        .expect("Always exists")
+        .expect("Compilation failure");
+    Ok(contract)
+}
+
+#[test]
+fn linked() -> anyhow::Result<()> {
+    let contract = get_contract()?;
+
+    let sizes = contract.sizes();
+    assert_eq!(sizes.bytes, contract.build.bytecode.len());
+    assert_eq!(
+        sizes.words * era_compiler_common::BYTE_LENGTH_FIELD,
+        sizes.bytes
+    );
+    assert_eq!(sizes.words % 2, 1);
+    assert!(!sizes.size_fallback);
+    contract.check_size()?;
+    contract.check_size_limit()?;
+
+    Ok(())
+}
+
+#[test]
+fn too_large() -> anyhow::Result<()> {
+    let mut contract = get_contract()?;
+
+    contract.build.bytecode = vec![0; (1 << 16) * era_compiler_common::BYTE_LENGTH_FIELD + 32];
+    let error = contract.check_size_limit().expect_err("Check must fail");
+    assert!(error.to_string().contains("exceeds the EraVM limit"));
+
+    Ok(())
+}
+
+fn link_too_large(fail_on_size_limit: bool) -> anyhow::Result<era_compiler_solidity::EraVMBuild> {
+    let mut contract = get_contract()?;
+
+    contract.build.bytecode = vec![0; (1 << 16) * era_compiler_common::BYTE_LENGTH_FIELD + 32];
+    let build = era_compiler_solidity::EraVMBuild::new(
+        BTreeMap::from([(contract.name.full_path.to_owned(), Ok(contract))]),
+        &mut vec![],
+    );
+    Ok(build.link(BTreeMap::new(), fail_on_size_limit))
+}
+
+#[test]
+fn too_large_link_warning() -> anyhow::Result<()> {
+    let build = link_too_large(false)?;
+
+    assert!(!build.has_errors());
+    assert!(build
+        .messages
+        .iter()
+        .any(|message| message.severity == "warning"
+            && message.message.contains("exceeds the EraVM limit")));
+
+    Ok(())
+}
+
+#[test]
+fn too_large_link_error() -> anyhow::Result<()> {
+    let build = link_too_large(true)?;
+
+    assert!(build.has_errors());
+
+    Ok(())
+}
+
+#[test]
+fn even_word_count() -> anyhow::Result<()> {
+    let mut contract = get_contract()?;
+
+    contract.build.bytecode = vec![0; 2 * era_compiler_common::BYTE_LENGTH_FIELD];
+    let error = contract.check_size().expect_err("Check must fail");
+    assert!(error.to_string().contains("must be odd"));
+
+    Ok(())
+}
+
+#[test]
+fn not_word_aligned() -> anyhow::Result<()> {
+    let mut contract = get_contract()?;
+
+    contract.build.bytecode = vec![0; era_compiler_common::BYTE_LENGTH_FIELD + 1];
+    let error = contract.check_size().expect_err("Check must fail");
+    assert!(error.to_string().contains("is not a multiple"));
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//!
+//! Unit tests for the EraVM source map.
+//!
+
+use era_compiler_solidity::source_map::location::Location;
+use era_compiler_solidity::SourceMap;
+
+#[test]
+fn yul_comment() {
+    assert_eq!(
+        Location::try_from_yul_comment("/// @src 0:10:25  \"contract Test {...\""),
+        Some(Location::new(0, 10, 25))
+    );
+    assert_eq!(
+        Location::try_from_yul_comment("/** @ast-id 7 @src -1:-1:-1 */"),
+        Some(Location::new(-1, -1, -1))
+    );
+    assert_eq!(Location::try_from_yul_comment("/// @ast-id 7"), None);
+}
+
+#[test]
+fn yul_resolve() {
+    let comments = vec![
+        (
+            era_yul::yul::lexer::token::location::Location::new(2, 1),
+            "/// @src 0:10:20\n".to_owned(),
+        ),
+        (
+            era_yul::yul::lexer::token::location::Location::new(3, 1),
+            "/// A comment without annotations\n".to_owned(),
+        ),
+        (
+            era_yul::yul::lexer::token::location::Location::new(5, 12),
+            "/** @src 1:30:40 */".to_owned(),
+        ),
+    ];
+    let source_map = SourceMap::from_yul_comments(comments.as_slice());
+
+    assert_eq!(source_map.resolve(1, 5), None);
+    assert_eq!(source_map.resolve(4, 5), Some(Location::new(0, 10, 20)));
+    assert_eq!(source_map.resolve(5, 13), Some(Location::new(1, 30, 40)));
+}
+
+#[test]
+fn encode() {
+    let comments = vec![
+        (
+            era_yul::yul::lexer::token::location::Location::new(1, 1),
+            "/// @src 0:10:20\n".to_owned(),
+        ),
+        (
+            era_yul::yul::lexer::token::location::Location::new(3, 1),
+            "/// @src 0:10:30\n".to_owned(),
+        ),
+    ];
+    let source_map = SourceMap::from_yul_comments(comments.as_slice());
+
+    let assembly = r#"
+	.text
+	.file	"Test.sol:Test"
+	.globl	__entry
+__entry:
+.func_begin0:
+	add	128, r0, r3
+	.loc	1 2 5 prologue_end
+	st.1	64, r3
+	add	r0, r0, r1 ; the same location
+	.loc	1 3 5
+	add	r1, r0, r2
+	.loc	1 0 0
+	ret
+	.rodata
+CPI0_0:
+	.cell	42
+"#;
+    assert_eq!(
+        source_map.encode(assembly),
+        "-1:-1:-1;10:10:0;;:20;-1:-1:-1"
+    );
+}
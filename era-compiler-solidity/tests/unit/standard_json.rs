@@ -267,3 +267,84 @@ fn standard_json_eravm_assembly_urls() {
         .object
         .is_empty())
 }
+
+#[test]
+fn standard_json_output_selection_skips_codegen() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_input = era_solc::StandardJsonInput::try_from(Some(
+        PathBuf::from("tests/data/standard_json_input/solidity_solc_output_selection.json")
+            .as_path(),
+    ))?;
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?;
+    let mut session = era_compiler_solidity::CompilerBuilder::new()
+        .solc(solc_compiler.executable)
+        .build()?;
+
+    let solc_output = session.standard_json(solc_input);
+    let is_compiled = |path: &str, name: &str| {
+        solc_output
+            .contracts
+            .get(path)
+            .and_then(|file| file.get(name))
+            .is_some_and(|contract| contract.eravm.is_some())
+    };
+    assert!(
+        is_compiled("A", "C"),
+        "The selected contract is not compiled"
+    );
+    assert!(
+        is_compiled("A", "D"),
+        "The factory dependency is not compiled"
+    );
+    assert!(
+        !is_compiled("A", "E"),
+        "The unselected contract is compiled"
+    );
+    assert!(
+        !is_compiled("B", "F"),
+        "The unselected contract is compiled"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_output_selection_abi_named_file() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_input = era_solc::StandardJsonInput::try_from(Some(
+        PathBuf::from("tests/data/standard_json_input/solidity_solc_output_selection_abi.json")
+            .as_path(),
+    ))?;
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?;
+    let mut session = era_compiler_solidity::CompilerBuilder::new()
+        .solc(solc_compiler.executable)
+        .build()?;
+
+    let solc_output = session.standard_json(solc_input);
+
+    let contract = solc_output
+        .contracts
+        .get("A")
+        .and_then(|file| file.get("C"))
+        .expect("The selected contract is missing");
+    assert!(!contract.abi.is_null(), "The ABI is missing");
+    assert!(
+        contract.eravm.is_none(),
+        "The ABI-only contract is compiled"
+    );
+    assert!(contract.metadata.is_null(), "The metadata is not pruned");
+    assert!(
+        !solc_output
+            .contracts
+            .get("B")
+            .and_then(|file| file.get("F"))
+            .is_some_and(|contract| contract.eravm.is_some()),
+        "The unselected contract is compiled"
+    );
+
+    Ok(())
+}
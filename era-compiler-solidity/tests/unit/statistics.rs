@@ -0,0 +1,43 @@
+//!
+//! Unit tests for the compilation statistics.
+//!
+
+use std::collections::BTreeMap;
+
+#[test]
+fn assembly() -> anyhow::Result<()> {
+    let assembly = std::fs::read_to_string(crate::common::TEST_ERAVM_ASSEMBLY_CONTRACT_PATH)?;
+
+    let statistics = era_compiler_solidity::EraVMAssemblyStatistics::from_assembly(&assembly);
+    assert_eq!(statistics.functions, 1);
+    assert_eq!(
+        statistics.instructions,
+        BTreeMap::from([
+            ("arithmetic".to_owned(), 5),
+            ("control".to_owned(), 6),
+            ("memory".to_owned(), 3),
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn category() {
+    for (mnemonic, category) in [
+        ("sub.s!", "arithmetic"),
+        ("xor", "bitwise"),
+        ("jump.eq", "control"),
+        ("log.swrite", "storage"),
+        ("context.gas_left", "context"),
+        ("ptr.add", "pointer"),
+        ("ldm.h", "memory"),
+        ("nop", "other"),
+    ] {
+        assert_eq!(
+            era_compiler_solidity::EraVMAssemblyStatistics::category(mnemonic),
+            category,
+            "Mnemonic `{mnemonic}`",
+        );
+    }
+}
@@ -0,0 +1,143 @@
+//!
+//! Unit tests for the bytecode verifier.
+//!
+
+///
+/// Returns a bytecode of `code` followed by the CBOR metadata with the specified versions.
+///
+fn bytecode(code: &[u8], zksolc_version: &str, solc_version: &str) -> Vec<u8> {
+    let value = format!("zksolc:{zksolc_version};solc:{solc_version}");
+    let mut cbor = vec![0xa1, 0x64];
+    cbor.extend_from_slice(b"solc");
+    cbor.push(0x78);
+    cbor.push(value.len() as u8);
+    cbor.extend_from_slice(value.as_bytes());
+
+    let mut bytecode = code.to_vec();
+    bytecode.extend_from_slice(cbor.as_slice());
+    bytecode.extend_from_slice((cbor.len() as u16).to_be_bytes().as_slice());
+    while bytecode.len() % era_compiler_common::BYTE_LENGTH_FIELD != 0 {
+        bytecode.push(0);
+    }
+    bytecode
+}
+
+fn standard_json(bytecode: &[u8]) -> era_solc::StandardJsonOutput {
+    let mut standard_json =
+        era_solc::StandardJsonOutput::new(&std::collections::BTreeMap::new(), &mut vec![]);
+    let mut contract = era_solc::StandardJsonOutputContract::default();
+    contract.eravm = Some(era_solc::StandardJsonOutputContractEraVM::new(
+        hex::encode(bytecode),
+        None,
+        None,
+        None,
+        None,
+        None,
+        era_solc::StandardJsonOutputContractEraVMSizes::new(bytecode.len(), 0, false),
+        None,
+    ));
+    standard_json
+        .contracts
+        .entry("Test.sol".to_owned())
+        .or_default()
+        .insert("Test".to_owned(), contract);
+    standard_json
+}
+
+#[test]
+fn metadata() {
+    let code = [0x11u8; 64];
+    let bytecode = bytecode(code.as_slice(), "1.5.16", "0.8.28");
+
+    let metadata = era_compiler_solidity::verifier::metadata::Metadata::parse(bytecode.as_slice())
+        .expect("Metadata not found");
+    assert_eq!(metadata.code_length, code.len());
+    assert_eq!(metadata.fields["zksolc"], "1.5.16");
+    assert_eq!(metadata.fields["solc"], "0.8.28");
+}
+
+#[test]
+fn exact() {
+    let bytecode = bytecode([0x11u8; 64].as_slice(), "1.5.16", "0.8.28");
+
+    let report = era_compiler_solidity::Verifier::verify(
+        &standard_json(bytecode.as_slice()),
+        bytecode.as_slice(),
+        false,
+    )
+    .expect("Verification failed");
+    assert!(report.verified);
+    assert_eq!(
+        report.status,
+        era_compiler_solidity::verifier::report::status::Status::Exact
+    );
+    assert_eq!(report.contract.as_deref(), Some("Test.sol:Test"));
+    assert!(report.differences.is_empty());
+}
+
+#[test]
+fn metadata_mismatch() {
+    let code = [0x11u8; 64];
+    let expected = bytecode(code.as_slice(), "1.5.16", "0.8.28");
+    let actual = bytecode(code.as_slice(), "1.5.16", "0.8.27");
+
+    let report = era_compiler_solidity::Verifier::verify(
+        &standard_json(expected.as_slice()),
+        actual.as_slice(),
+        false,
+    )
+    .expect("Verification failed");
+    assert!(!report.verified);
+    assert_eq!(
+        report.status,
+        era_compiler_solidity::verifier::report::status::Status::Partial
+    );
+    assert_eq!(report.metadata_mismatches.len(), 1);
+    assert_eq!(report.metadata_mismatches[0].field, "solc");
+    assert_eq!(
+        report.metadata_mismatches[0].expected.as_deref(),
+        Some("0.8.28")
+    );
+    assert_eq!(
+        report.metadata_mismatches[0].actual.as_deref(),
+        Some("0.8.27")
+    );
+
+    let report = era_compiler_solidity::Verifier::verify(
+        &standard_json(expected.as_slice()),
+        actual.as_slice(),
+        true,
+    )
+    .expect("Verification failed");
+    assert!(report.verified);
+}
+
+#[test]
+fn code_mismatch() {
+    let expected_code = [0x11u8; 64];
+    let mut actual_code = expected_code;
+    actual_code[4..8].copy_from_slice([0x22u8; 4].as_slice());
+    actual_code[32] = 0x33;
+    let expected = bytecode(expected_code.as_slice(), "1.5.16", "0.8.28");
+    let actual = bytecode(actual_code.as_slice(), "1.5.16", "0.8.28");
+
+    let report = era_compiler_solidity::Verifier::verify(
+        &standard_json(expected.as_slice()),
+        actual.as_slice(),
+        true,
+    )
+    .expect("Verification failed");
+    assert!(!report.verified);
+    assert_eq!(
+        report.status,
+        era_compiler_solidity::verifier::report::status::Status::Mismatch
+    );
+    assert_eq!(report.contract.as_deref(), Some("Test.sol:Test"));
+    assert_eq!(
+        report.differences,
+        vec![
+            era_compiler_solidity::verifier::report::range::Range::new(4, 8),
+            era_compiler_solidity::verifier::report::range::Range::new(32, 33),
+        ]
+    );
+}
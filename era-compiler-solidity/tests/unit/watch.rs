@@ -0,0 +1,157 @@
+//!
+//! Unit tests for the watch mode.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use era_compiler_solidity::project::Project;
+use era_solc::CollectableError;
+
+///
+/// Builds the Yul `sources` with the watch mode state and returns the unlinked bytecodes.
+///
+fn build(
+    watch: &mut era_compiler_solidity::Watch,
+    sources: BTreeMap<String, String>,
+) -> anyhow::Result<BTreeMap<String, Vec<u8>>> {
+    crate::common::setup()?;
+
+    era_compiler_llvm_context::initialize_target();
+
+    let sources = sources
+        .into_iter()
+        .map(|(path, source)| (path, era_solc::StandardJsonInputSource::from(source)))
+        .collect();
+
+    let project = Project::try_from_yul_sources(
+        sources,
+        era_compiler_common::Libraries::default(),
+        None,
+        None,
+        None,
+    )?;
+    let build = watch.compile_to_eravm(
+        project,
+        &mut vec![],
+        false,
+        era_compiler_common::MetadataHashType::IPFS,
+        false,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+        vec![],
+        false,
+        false,
+        false,
+        None,
+        era_compiler_solidity::ProcessBackend::default(),
+        None,
+    )?;
+    build.check_errors()?;
+
+    Ok(build
+        .results
+        .into_iter()
+        .map(|(path, result)| (path, result.expect("Always valid").build.bytecode))
+        .collect())
+}
+
+#[test]
+fn unchanged() {
+    let sources = crate::common::read_sources(&[
+        crate::common::TEST_YUL_CONTRACT_FACTORY_PATH,
+        crate::common::TEST_YUL_CONTRACT_DEPENDENCY_PATH,
+    ]);
+
+    let mut watch = era_compiler_solidity::Watch::new(
+        era_compiler_solidity::Watch::DEFAULT_INTERVAL,
+        &[],
+        &BTreeSet::new(),
+    );
+    let cold = build(&mut watch, sources.clone()).expect("Build failure");
+    let warm = build(&mut watch, sources).expect("Build failure");
+
+    assert_eq!(cold, warm, "Unchanged contracts must be reused");
+}
+
+#[test]
+fn changed() {
+    let mut sources = crate::common::read_sources(&[
+        crate::common::TEST_YUL_CONTRACT_FACTORY_PATH,
+        crate::common::TEST_YUL_CONTRACT_DEPENDENCY_PATH,
+    ]);
+
+    let mut watch = era_compiler_solidity::Watch::new(
+        era_compiler_solidity::Watch::DEFAULT_INTERVAL,
+        &[],
+        &BTreeSet::new(),
+    );
+    let cold = build(&mut watch, sources.clone()).expect("Build failure");
+
+    let dependency = sources
+        .get_mut(crate::common::TEST_YUL_CONTRACT_DEPENDENCY_PATH)
+        .expect("Always exists");
+    *dependency = dependency.replace("mstore(0, 42)", "mstore(0, 43)");
+    let warm = build(&mut watch, sources).expect("Build failure");
+
+    let factory_path = format!("{}:Factory", crate::common::TEST_YUL_CONTRACT_FACTORY_PATH);
+    let dependency_path = format!(
+        "{}:Dependency",
+        crate::common::TEST_YUL_CONTRACT_DEPENDENCY_PATH
+    );
+    assert_eq!(cold.get(&factory_path), warm.get(&factory_path));
+    assert_ne!(cold.get(&dependency_path), warm.get(&dependency_path));
+}
+
+#[test]
+fn seeded_on_failure() -> anyhow::Result<()> {
+    let tmp_dir = tempfile::TempDir::with_prefix("zksolc_watch")?;
+    let path = tmp_dir.path().join("Test.sol");
+    std::fs::write(path.as_path(), "contract Test {")?;
+
+    let watch = era_compiler_solidity::Watch::new(
+        std::time::Duration::from_millis(10),
+        &[PathBuf::from(path.as_path())],
+        &BTreeSet::new(),
+    );
+
+    let writer = {
+        let path = path.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(1100));
+            std::fs::write(path, "contract Test {}").expect("Always valid");
+        })
+    };
+    watch.wait_for_changes()?;
+    writer.join().expect("Always valid");
+
+    Ok(())
+}
+
+#[test]
+fn remapped_directory_nested_file() -> anyhow::Result<()> {
+    let tmp_dir = tempfile::TempDir::with_prefix("zksolc_watch")?;
+    let directory = tmp_dir.path().join("lib").join("nested");
+    std::fs::create_dir_all(directory.as_path())?;
+    let path = directory.join("Library.sol");
+    std::fs::write(path.as_path(), "library Library {")?;
+
+    let remapping = format!("lib/={}", tmp_dir.path().join("lib").to_string_lossy());
+    let watch = era_compiler_solidity::Watch::new(
+        std::time::Duration::from_millis(10),
+        &[],
+        &BTreeSet::from([remapping]),
+    );
+
+    let writer = {
+        let path = path.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(1100));
+            std::fs::write(path, "library Library {}").expect("Always valid");
+        })
+    };
+    watch.wait_for_changes()?;
+    writer.join().expect("Always valid");
+
+    Ok(())
+}
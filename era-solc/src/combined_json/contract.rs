@@ -5,6 +5,8 @@
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use crate::combined_json::eravm_metadata::EraVMMetadata;
+
 ///
 /// The contract.
 ///
@@ -42,12 +44,23 @@ pub struct Contract {
     /// LLVM-generated assembly.
     #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
     pub assembly: Option<String>,
+    /// The EraVM source map.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub srcmap: Option<String>,
     /// Hexadecimal deploy bytecode segment output.
     #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
     pub bin: Option<String>,
     /// Hexadecimal runtime bytecode segment output.
     #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
     pub bin_runtime: Option<String>,
+    /// The EraVM bytecode hash.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub eravm_hash: Option<String>,
+    /// The EraVM-specific metadata.
+    ///
+    /// Only emitted with the `metadata` selector, as it summarizes the latter.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub eravm_metadata: Option<EraVMMetadata>,
 
     /// The unlinked factory dependencies.
     #[serde(default, skip_deserializing)]
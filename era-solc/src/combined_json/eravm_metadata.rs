@@ -0,0 +1,43 @@
+//!
+//! The `solc --combined-json` contract EraVM metadata.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The `solc --combined-json` contract EraVM metadata.
+///
+/// Summarizes the EraVM-specific parts of the metadata, which are hidden in the `metadata` string.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct EraVMMetadata {
+    /// The metadata hash type.
+    pub hash_type: String,
+    /// The entries of the CBOR metadata appended to the bytecode, including the metadata hash,
+    /// or `None` if it is not appended.
+    pub cbor: Option<BTreeMap<String, String>>,
+    /// The optimizer settings.
+    pub optimizer_settings: serde_json::Value,
+    /// The extra LLVM options.
+    pub llvm_options: Vec<String>,
+}
+
+impl EraVMMetadata {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        hash_type: String,
+        cbor: Option<BTreeMap<String, String>>,
+        optimizer_settings: serde_json::Value,
+        llvm_options: Vec<String>,
+    ) -> Self {
+        Self {
+            hash_type,
+            cbor,
+            optimizer_settings,
+            llvm_options,
+        }
+    }
+}
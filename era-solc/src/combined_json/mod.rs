@@ -3,9 +3,12 @@
 //!
 
 pub mod contract;
+pub mod eravm_metadata;
+pub mod profile;
 pub mod selector;
 
 use std::collections::BTreeMap;
+use std::path::Component;
 use std::path::Path;
 
 use self::contract::Contract;
@@ -45,6 +48,16 @@ impl CombinedJson {
         }
     }
 
+    ///
+    /// Removes EVM artifacts that do not make sense for EraVM, such as the runtime bytecode,
+    /// which is a copy of the deploy bytecode.
+    ///
+    pub fn remove_evm_artifacts(&mut self) {
+        for contract in self.contracts.values_mut() {
+            contract.bin_runtime = None;
+        }
+    }
+
     ///
     /// Writes the JSON to the specified directory.
     ///
@@ -70,4 +83,61 @@ impl CombinedJson {
 
         Ok(())
     }
+
+    ///
+    /// Writes the JSON of each contract to a separate file in the specified directory.
+    ///
+    /// The files are written to `<directory>/<source path>/<contract name>.json`, and contain
+    /// the same document as the combined JSON, with only one contract and its source.
+    ///
+    /// The source path is mirrored as is, so contracts with the same name in files with the same
+    /// name in different directories do not collide. Root, prefix, and `.`/`..` components are
+    /// dropped to keep the files inside the output directory.
+    ///
+    pub fn write_to_directory_per_contract(
+        self,
+        output_directory: &Path,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        for (full_path, contract) in self.contracts.into_iter() {
+            let (path, name) = full_path
+                .rsplit_once(':')
+                .unwrap_or((full_path.as_str(), full_path.as_str()));
+            let mut file_path = output_directory.to_owned();
+            file_path.extend(
+                Path::new(path)
+                    .components()
+                    .filter(|component| matches!(component, Component::Normal(_))),
+            );
+            std::fs::create_dir_all(file_path.as_path())?;
+            file_path.push(format!("{name}.{}", era_compiler_common::EXTENSION_JSON));
+
+            if file_path.exists() && !overwrite {
+                anyhow::bail!(
+                    "Refusing to overwrite an existing file {file_path:?} (use --overwrite to force)."
+                );
+            }
+
+            let sources = match self.sources.get(path) {
+                Some(source) => serde_json::json!({ path: source }),
+                None => serde_json::Value::Null,
+            };
+            let combined_json = Self {
+                contracts: BTreeMap::from([(full_path, contract)]),
+                source_list: self.source_list.clone(),
+                sources,
+                version: self.version.clone(),
+                zk_version: self.zk_version.clone(),
+            };
+            std::fs::write(
+                file_path.as_path(),
+                serde_json::to_vec(&combined_json)
+                    .expect("Always valid")
+                    .as_slice(),
+            )
+            .map_err(|error| anyhow::anyhow!("File {file_path:?} writing: {error}"))?;
+        }
+
+        Ok(())
+    }
 }
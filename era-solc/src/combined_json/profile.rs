@@ -0,0 +1,45 @@
+//!
+//! The `solc --combined-json` output profile.
+//!
+
+use std::str::FromStr;
+
+///
+/// The `solc --combined-json` output profile.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Profile {
+    /// The output compatible with `solc` tooling.
+    #[default]
+    Compatible,
+    /// The output without fields that do not make sense for EraVM, such as `bin-runtime`.
+    Strict,
+}
+
+impl FromStr for Profile {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "compatible" => Ok(Self::Compatible),
+            "strict" => Ok(Self::Strict),
+            string => anyhow::bail!(
+                "Invalid combined JSON profile: `{string}`. Available options: {}.",
+                [Self::Compatible, Self::Strict]
+                    .into_iter()
+                    .map(|profile| profile.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Compatible => write!(f, "compatible"),
+            Self::Strict => write!(f, "strict"),
+        }
+    }
+}
@@ -40,6 +40,9 @@ pub enum Selector {
     /// The assembly.
     #[serde(rename = "assembly", skip_serializing)]
     Assembly,
+    /// The EraVM source map.
+    #[serde(rename = "srcmap", skip_serializing)]
+    SourceMap,
 
     /// The deploy bytecode.
     #[serde(rename = "bin", skip_serializing)]
@@ -66,7 +69,7 @@ impl Selector {
     pub fn is_source_solc(&self) -> bool {
         !matches!(
             self,
-            Self::Assembly | Self::Bytecode | Self::BytecodeRuntime
+            Self::Assembly | Self::SourceMap | Self::Bytecode | Self::BytecodeRuntime
         )
     }
 }
@@ -90,6 +93,7 @@ impl FromStr for Selector {
             "bin-runtime" => Ok(Self::BytecodeRuntime),
 
             "assembly" => Ok(Self::Assembly),
+            "srcmap" => Ok(Self::SourceMap),
 
             selector => anyhow::bail!("{selector}"),
         }
@@ -113,6 +117,7 @@ impl std::fmt::Display for Selector {
             Self::BytecodeRuntime => write!(f, "bin-runtime"),
 
             Self::Assembly => write!(f, "assembly"),
+            Self::SourceMap => write!(f, "srcmap"),
         }
     }
 }
@@ -15,6 +15,8 @@ pub mod standard_json;
 pub mod version;
 
 pub use self::combined_json::contract::Contract as CombinedJsonContract;
+pub use self::combined_json::eravm_metadata::EraVMMetadata as CombinedJsonEraVMMetadata;
+pub use self::combined_json::profile::Profile as CombinedJsonProfile;
 pub use self::combined_json::selector::Selector as CombinedJsonSelector;
 pub use self::combined_json::CombinedJson;
 pub use self::solc::Compiler;
@@ -30,13 +32,19 @@ pub use self::standard_json::input::settings::warning_type::WarningType as Stand
 pub use self::standard_json::input::settings::Settings as StandardJsonInputSettings;
 pub use self::standard_json::input::source::Source as StandardJsonInputSource;
 pub use self::standard_json::input::Input as StandardJsonInput;
+pub use self::standard_json::output::contract::eravm::link_reference::LinkReference as StandardJsonOutputContractEraVMLinkReference;
+pub use self::standard_json::output::contract::eravm::sizes::Sizes as StandardJsonOutputContractEraVMSizes;
+pub use self::standard_json::output::contract::eravm::statistics::Statistics as StandardJsonOutputContractEraVMStatistics;
 pub use self::standard_json::output::contract::eravm::EraVM as StandardJsonOutputContractEraVM;
 pub use self::standard_json::output::contract::evm::bytecode::Bytecode as StandardJsonOutputContractEVMBytecode;
 pub use self::standard_json::output::contract::evm::extra_metadata::recursive_function::RecursiveFunction as StandardJsonOutputContractEVMExtraMetadataRecursiveFunction;
 pub use self::standard_json::output::contract::evm::extra_metadata::ExtraMetadata as StandardJsonOutputContractEVMExtraMetadata;
 pub use self::standard_json::output::contract::evm::EVM as StandardJsonOutputContractEVM;
+pub use self::standard_json::output::contract::missing_library::location::Location as StandardJsonOutputContractMissingLibraryLocation;
+pub use self::standard_json::output::contract::missing_library::MissingLibrary as StandardJsonOutputContractMissingLibrary;
 pub use self::standard_json::output::contract::Contract as StandardJsonOutputContract;
 pub use self::standard_json::output::error::collectable::Collectable as CollectableError;
+pub use self::standard_json::output::error::mapped_location::MappedLocation as StandardJsonOutputErrorMappedLocation;
 pub use self::standard_json::output::error::source_location::SourceLocation as StandardJsonOutputErrorSourceLocation;
 pub use self::standard_json::output::error::Error as StandardJsonOutputError;
 pub use self::standard_json::output::source::Source as StandardJsonOutputSource;
@@ -10,6 +10,7 @@ pub mod selection;
 pub mod warning_type;
 
 use std::collections::BTreeSet;
+use std::path::PathBuf;
 
 use self::codegen::Codegen;
 use self::error_type::ErrorType;
@@ -68,6 +69,10 @@ pub struct Settings {
     #[serde(default, skip_serializing)]
     pub suppressed_warnings: Vec<WarningType>,
 
+    /// The directory of the persistent contract build cache.
+    #[serde(default, skip_serializing)]
+    pub cache_dir: Option<PathBuf>,
+
     /// Whether to enable the missing libraries detection mode.
     /// Deprecated in favor of post-compile-time linking.
     #[serde(default, rename = "detectMissingLibraries", skip_serializing)]
@@ -122,6 +127,8 @@ impl Settings {
             suppressed_errors,
             suppressed_warnings,
 
+            cache_dir: None,
+
             detect_missing_libraries,
             via_ir: if via_ir { Some(true) } else { None },
         }
@@ -2,6 +2,7 @@
 //! The `solc --standard-json` output file selection.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
 use crate::standard_json::input::settings::selection::selector::Selector;
@@ -17,6 +18,9 @@ pub struct File {
     /// The per-contract output selections.
     #[serde(default, rename = "*", skip_serializing_if = "HashSet::is_empty")]
     pub per_contract: HashSet<Selector>,
+    /// The output selections of contracts specified by name.
+    #[serde(flatten)]
+    pub contracts: BTreeMap<String, HashSet<Selector>>,
 }
 
 impl File {
@@ -39,6 +43,7 @@ impl File {
         Self {
             per_file,
             per_contract,
+            contracts: BTreeMap::new(),
         }
     }
 
@@ -48,6 +53,9 @@ impl File {
     pub fn extend(&mut self, other: Self) -> &mut Self {
         self.per_file.extend(other.per_file);
         self.per_contract.extend(other.per_contract);
+        for (name, flags) in other.contracts.into_iter() {
+            self.contracts.entry(name).or_default().extend(flags);
+        }
         self
     }
 
@@ -70,35 +78,59 @@ impl File {
         let mut unset_per_contract = HashSet::with_capacity(required_per_contract.len());
 
         for flag in required_per_file {
-            if !self.per_file.contains(&flag) {
+            if !self.contains(&flag) {
                 unset_per_file.insert(flag);
             }
         }
         for flag in required_per_contract {
-            if !self.per_contract.contains(&flag) {
+            if !self.contains(&flag) {
                 unset_per_contract.insert(flag);
             }
         }
         Self {
             per_file: unset_per_file,
             per_contract: unset_per_contract,
+            contracts: BTreeMap::new(),
         }
     }
 
     ///
-    /// Whether the flag is requested.
+    /// Whether the flag is requested for any contract.
     ///
     pub fn contains(&self, flag: &Selector) -> bool {
         match flag {
             flag @ Selector::AST => self.per_file.contains(flag),
-            flag => self.per_contract.contains(flag),
+            flag => {
+                self.per_contract.contains(flag)
+                    || self.contracts.values().any(|flags| flags.contains(flag))
+            }
         }
     }
 
+    ///
+    /// Whether any flag requiring EraVM codegen is requested for the contract `name`.
+    ///
+    pub fn requires_codegen(&self, name: &str) -> bool {
+        self.per_contract
+            .iter()
+            .chain(self.contracts.get(name).into_iter().flatten())
+            .any(Selector::requires_codegen)
+    }
+
+    ///
+    /// Whether any flag requiring EraVM codegen is requested for any contract.
+    ///
+    pub fn is_codegen_requested(&self) -> bool {
+        self.per_contract
+            .iter()
+            .chain(self.contracts.values().flatten())
+            .any(Selector::requires_codegen)
+    }
+
     ///
     /// Checks whether the selection is empty.
     ///
     pub fn is_empty(&self) -> bool {
-        self.per_file.is_empty() && self.per_contract.is_empty()
+        self.per_file.is_empty() && self.per_contract.is_empty() && self.contracts.is_empty()
     }
 }
@@ -5,6 +5,8 @@
 pub mod file;
 pub mod selector;
 
+use std::collections::BTreeMap;
+
 use crate::standard_json::input::settings::codegen::Codegen as StandardJsonInputSettingsCodegen;
 
 use self::file::File as FileSelection;
@@ -15,9 +17,12 @@ use self::selector::Selector;
 ///
 #[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct Selection {
-    /// Only the 'all' wildcard is available for robustness reasons.
+    /// The selection of all files.
     #[serde(default, rename = "*", skip_serializing_if = "FileSelection::is_empty")]
     pub all: FileSelection,
+    /// The selections of files specified by path.
+    #[serde(flatten)]
+    pub files: BTreeMap<String, FileSelection>,
 }
 
 impl Selection {
@@ -27,6 +32,7 @@ impl Selection {
     pub fn new(flags: Vec<Selector>) -> Self {
         Self {
             all: FileSelection::new(flags),
+            files: BTreeMap::new(),
         }
     }
 
@@ -54,6 +60,9 @@ impl Selection {
     ///
     pub fn extend(&mut self, other: Self) -> &mut Self {
         self.all.extend(other.all);
+        for (path, file) in other.files.into_iter() {
+            self.files.entry(path).or_default().extend(file);
+        }
         self
     }
 
@@ -63,16 +72,65 @@ impl Selection {
     ///
     /// Afterwards, the flags are used to prune JSON output before returning it.
     ///
+    /// The files selected by path get their own flags, as the flags requested for
+    /// other files must not prevent pruning them.
+    ///
     pub fn selection_to_prune(&self) -> Self {
-        Self {
-            all: self.all.selection_to_prune(),
-        }
+        let all = self.all.selection_to_prune();
+        let files = self
+            .files
+            .iter()
+            .map(|(path, file)| {
+                let mut file = file.selection_to_prune();
+                file.per_file.retain(|flag| all.per_file.contains(flag));
+                file.per_contract
+                    .retain(|flag| all.per_contract.contains(flag));
+                (path.to_owned(), file)
+            })
+            .collect();
+        Self { all, files }
+    }
+
+    ///
+    /// Whether the flag is to be pruned from the output of file `path`.
+    ///
+    /// Must only be called on the selection returned by `selection_to_prune`.
+    ///
+    pub fn is_pruned(&self, path: &str, flag: &Selector) -> bool {
+        self.files
+            .get(path)
+            .map_or_else(|| self.all.contains(flag), |file| file.contains(flag))
     }
 
     ///
-    /// Whether the flag is requested.
+    /// Whether the flag is requested for any file or contract.
     ///
     pub fn contains(&self, flag: &Selector) -> bool {
-        self.all.contains(flag)
+        self.all.contains(flag) || self.files.values().any(|file| file.contains(flag))
+    }
+
+    ///
+    /// Whether any file is selected by path, or any flag requiring EraVM codegen is requested
+    /// for any file or contract.
+    ///
+    /// If not, all contracts are compiled for backward compatibility, as bytecode has always
+    /// been returned regardless of the selection.
+    ///
+    pub fn is_codegen_selective(&self) -> bool {
+        !self.files.is_empty()
+            || std::iter::once(&self.all)
+                .chain(self.files.values())
+                .any(FileSelection::is_codegen_requested)
+    }
+
+    ///
+    /// Whether any flag requiring EraVM codegen is requested for the contract `name` in file `path`.
+    ///
+    pub fn requires_codegen(&self, path: &str, name: &str) -> bool {
+        self.all.requires_codegen(name)
+            || self
+                .files
+                .get(path)
+                .is_some_and(|file| file.requires_codegen(name))
     }
 }
@@ -40,9 +40,58 @@ pub enum Selector {
     #[serde(rename = "evm.methodIdentifiers")]
     MethodIdentifiers,
 
+    /// The EraVM bytecode.
+    #[serde(rename = "eravm.bytecode")]
+    EraVMBytecode,
     /// The EraVM assembly.
     #[serde(rename = "eravm.assembly")]
     EraVMAssembly,
+    /// The project deployment plan.
+    #[serde(rename = "eravm.deploymentPlan")]
+    EraVMDeploymentPlan,
+    /// The EraVM source map.
+    #[serde(rename = "eravm.sourceMap")]
+    EraVMSourceMap,
+    /// The EraVM unoptimized LLVM IR.
+    #[serde(rename = "eravm.llvmIr")]
+    EraVMLLVMIR,
+    /// The EraVM optimized LLVM IR.
+    #[serde(rename = "eravm.llvmIrOptimized")]
+    EraVMLLVMIROptimized,
+    /// The Yul IR passed to the EraVM code generator.
+    #[serde(rename = "eravm.yulOptimized")]
+    EraVMYulOptimized,
+    /// The EraVM compilation statistics.
+    #[serde(rename = "eravm.statistics")]
+    EraVMStatistics,
+    /// The EraVM library address references of unlinked bytecode.
+    #[serde(rename = "eravm.linkReferences")]
+    EraVMLinkReferences,
+    /// The EraVM factory dependency hash references of unlinked bytecode.
+    #[serde(rename = "eravm.factoryDependencyReferences")]
+    EraVMFactoryDependencyReferences,
+}
+
+impl Selector {
+    ///
+    /// Whether the selector requires the contract to be compiled to EraVM.
+    ///
+    pub fn requires_codegen(&self) -> bool {
+        matches!(
+            self,
+            Self::EVM
+                | Self::EraVMBytecode
+                | Self::EraVMAssembly
+                | Self::EraVMDeploymentPlan
+                | Self::EraVMSourceMap
+                | Self::EraVMLLVMIR
+                | Self::EraVMLLVMIROptimized
+                | Self::EraVMYulOptimized
+                | Self::EraVMStatistics
+                | Self::EraVMLinkReferences
+                | Self::EraVMFactoryDependencyReferences
+        )
+    }
 }
 
 impl From<StandardJsonInputSettingsCodegen> for Selector {
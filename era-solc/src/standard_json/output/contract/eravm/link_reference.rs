@@ -0,0 +1,25 @@
+//!
+//! The `solc --standard-json` output contract EraVM link reference.
+//!
+
+///
+/// The `solc --standard-json` output contract EraVM link reference.
+///
+/// Shaped like `solc` EVM `linkReferences` entries.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LinkReference {
+    /// The byte offset of the reference in the linked bytecode.
+    pub start: usize,
+    /// The reference length in bytes.
+    pub length: usize,
+}
+
+impl LinkReference {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: usize, length: usize) -> Self {
+        Self { start, length }
+    }
+}
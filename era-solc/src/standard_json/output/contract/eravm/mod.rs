@@ -0,0 +1,89 @@
+//!
+//! The `solc --standard-json` output contract EraVM data.
+//!
+
+pub mod link_reference;
+pub mod sizes;
+pub mod statistics;
+
+use std::collections::BTreeMap;
+
+use self::link_reference::LinkReference;
+use self::sizes::Sizes;
+use self::statistics::Statistics;
+
+///
+/// The `solc --standard-json` output contract EraVM data.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EraVM {
+    /// The contract bytecode.
+    pub bytecode: String,
+    /// The contract text assembly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assembly: Option<String>,
+    /// The contract source map, with an entry per instruction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_map: Option<String>,
+    /// The contract unoptimized LLVM IR.
+    #[serde(rename = "llvmIr", default, skip_serializing_if = "Option::is_none")]
+    pub llvm_ir: Option<String>,
+    /// The contract optimized LLVM IR.
+    #[serde(
+        rename = "llvmIrOptimized",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub llvm_ir_optimized: Option<String>,
+    /// The contract Yul IR passed to the code generator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yul_optimized: Option<String>,
+    /// The contract bytecode sizes.
+    pub sizes: Sizes,
+    /// The contract compilation statistics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statistics: Option<Statistics>,
+    /// The library address references of the unlinked bytecode, as a mapping from file paths
+    /// to library names to offsets in the linked bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_references: Option<BTreeMap<String, BTreeMap<String, Vec<LinkReference>>>>,
+    /// The factory dependency hash references of the unlinked bytecode, as a mapping from file paths
+    /// to contract names to offsets in the linked bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory_dependency_references:
+        Option<BTreeMap<String, BTreeMap<String, Vec<LinkReference>>>>,
+    /// The linked bytecode with all references filled with zeros, which the reference offsets point into.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytecode_template: Option<String>,
+}
+
+impl EraVM {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        bytecode: String,
+        assembly: Option<String>,
+        source_map: Option<String>,
+        llvm_ir: Option<String>,
+        llvm_ir_optimized: Option<String>,
+        yul_optimized: Option<String>,
+        sizes: Sizes,
+        statistics: Option<Statistics>,
+    ) -> Self {
+        Self {
+            bytecode,
+            assembly,
+            source_map,
+            llvm_ir,
+            llvm_ir_optimized,
+            yul_optimized,
+            sizes,
+            statistics,
+            link_references: None,
+            factory_dependency_references: None,
+            bytecode_template: None,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! The `solc --standard-json` output contract EraVM bytecode sizes.
+//!
+
+///
+/// The `solc --standard-json` output contract EraVM bytecode sizes.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Sizes {
+    /// The bytecode size in bytes.
+    pub bytes: usize,
+    /// The bytecode size in 32-byte words.
+    pub words: usize,
+    /// Whether the contract was recompiled with `-Oz` due to the size fallback.
+    #[serde(default)]
+    pub size_fallback: bool,
+}
+
+impl Sizes {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(bytes: usize, words: usize, size_fallback: bool) -> Self {
+        Self {
+            bytes,
+            words,
+            size_fallback,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//!
+//! The `solc --standard-json` output contract EraVM compilation statistics.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The `solc --standard-json` output contract EraVM compilation statistics.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statistics {
+    /// The compilation wall time in milliseconds, including the subprocess overhead.
+    pub wall_time_ms: u64,
+    /// The time of the LLVM IR generation from the source IR in milliseconds.
+    pub ir_generation_time_ms: u64,
+    /// The time of the LLVM optimizations and EraVM code generation in milliseconds.
+    ///
+    /// Both are run by LLVM as a single pipeline, so they are measured together.
+    pub llvm_time_ms: u64,
+    /// The peak memory usage of the compilation subprocess in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_memory_bytes: Option<u64>,
+    /// The number of functions in the EraVM assembly.
+    pub functions: usize,
+    /// The number of instructions in the EraVM assembly by category.
+    pub instructions: BTreeMap<String, usize>,
+    /// Whether the contract was recompiled with `-Oz` due to the size fallback.
+    pub size_fallback: bool,
+    /// Whether the build has been taken from the cache, so the values describe the original compilation.
+    #[serde(default)]
+    pub cached: bool,
+}
+
+impl Statistics {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        ir_generation_time_ms: u64,
+        llvm_time_ms: u64,
+        functions: usize,
+        instructions: BTreeMap<String, usize>,
+        size_fallback: bool,
+    ) -> Self {
+        Self {
+            wall_time_ms: 0,
+            ir_generation_time_ms,
+            llvm_time_ms,
+            peak_memory_bytes: None,
+            functions,
+            instructions,
+            size_fallback,
+            cached: false,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//!
+//! The `solc --standard-json` output contract missing library location.
+//!
+
+///
+/// The `solc --standard-json` output contract missing library location.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Location {
+    /// The `linkersymbol` call location in Yul.
+    Yul {
+        /// The line number, starting from 1.
+        line: usize,
+        /// The column number, starting from 1.
+        column: usize,
+    },
+    /// The `PUSHLIB` instruction location in EVM legacy assembly.
+    EVMLA {
+        /// The source code identifier.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        source: Option<isize>,
+        /// The source code location begin.
+        begin: isize,
+        /// The source code location end.
+        end: isize,
+    },
+}
@@ -0,0 +1,41 @@
+//!
+//! The `solc --standard-json` output contract missing library.
+//!
+
+pub mod location;
+
+use self::location::Location;
+
+///
+/// The `solc --standard-json` output contract missing library.
+///
+/// Tells where the library is referenced, and through which libraries it is required
+/// if the contract does not reference it directly.
+///
+/// Only the directly referenced libraries, that is, the ones with a single-element `chain`,
+/// are listed in `missingLibraries`. The other ones are missing libraries of those libraries,
+/// which must be deployed before the libraries themselves can be linked and deployed.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingLibrary {
+    /// The full name of the contract or library whose IR references the library.
+    pub referenced_by: String,
+    /// The locations of all references in the IR of `referenced_by`.
+    pub locations: Vec<Location>,
+    /// The chain of libraries from the directly referenced one to this one, both inclusive.
+    pub chain: Vec<String>,
+}
+
+impl MissingLibrary {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(referenced_by: String, locations: Vec<Location>, chain: Vec<String>) -> Self {
+        Self {
+            referenced_by,
+            locations,
+            chain,
+        }
+    }
+}
@@ -4,12 +4,14 @@
 
 pub mod eravm;
 pub mod evm;
+pub mod missing_library;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use self::eravm::EraVM;
 use self::evm::EVM;
+use self::missing_library::MissingLibrary;
 
 ///
 /// The `solc --standard-json` output contract.
@@ -39,7 +41,7 @@ pub struct Contract {
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub ir_optimized: String,
     /// The EraVM data of the contract.
-    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub eravm: Option<EraVM>,
     /// The EVM data of the contract.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -52,11 +54,20 @@ pub struct Contract {
     #[serde(default, skip_deserializing)]
     pub factory_dependencies_unlinked: BTreeSet<String>,
     /// Linked factory dependencies.
-    #[serde(default, skip_deserializing)]
+    /// Deserialized to allow linking the `zksolc` standard JSON output again.
+    #[serde(default)]
     pub factory_dependencies: BTreeMap<String, String>,
     /// Missing linkable libraries.
     #[serde(default, skip_deserializing)]
     pub missing_libraries: BTreeSet<String>,
+    /// Locations of missing library references, including the ones required through other libraries.
+    /// The keys are a superset of `missing_libraries`, which only lists the directly referenced ones.
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        skip_deserializing
+    )]
+    pub missing_libraries_details: BTreeMap<String, MissingLibrary>,
     /// Binary object format.
     #[serde(default, skip_deserializing)]
     pub object_format: Option<era_compiler_common::ObjectFormat>,
@@ -80,5 +91,6 @@ impl Contract {
             && self.factory_dependencies_unlinked.is_empty()
             && self.factory_dependencies.is_empty()
             && self.missing_libraries.is_empty()
+            && self.missing_libraries_details.is_empty()
     }
 }
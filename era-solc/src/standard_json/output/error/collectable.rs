@@ -48,6 +48,15 @@ pub trait Collectable {
         );
     }
 
+    ///
+    /// Moves warnings and copies errors to `messages`, and bails if there is at least one error.
+    ///
+    fn collect_messages(&mut self, messages: &mut Vec<Error>) -> anyhow::Result<()> {
+        messages.extend(self.take_warnings());
+        messages.extend(self.errors().into_iter().cloned());
+        self.check_errors()
+    }
+
     ///
     /// Checks for errors, exiting the application if there is at least one error.
     ///
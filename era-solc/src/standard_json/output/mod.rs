@@ -38,6 +38,13 @@ pub struct Output {
     /// The compilation errors and warnings.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<JsonOutputError>,
+    /// The deployment plan of the project.
+    #[serde(
+        default,
+        rename = "deploymentPlan",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub deployment_plan: Option<serde_json::Value>,
 
     /// The `solc` compiler version.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -70,6 +77,7 @@ impl Output {
             contracts: BTreeMap::new(),
             sources,
             errors: std::mem::take(messages),
+            deployment_plan: None,
 
             version: None,
             long_version: None,
@@ -87,6 +95,7 @@ impl Output {
             contracts: BTreeMap::new(),
             sources: BTreeMap::new(),
             errors: messages,
+            deployment_plan: None,
 
             version: None,
             long_version: None,
@@ -98,30 +107,43 @@ impl Output {
     /// Prunes the output JSON and prints it to stdout.
     ///
     pub fn write_and_exit(mut self, selection_to_prune: Selection) -> ! {
-        let sources = self.sources.values_mut().collect::<Vec<&mut Source>>();
-        for source in sources.into_iter() {
-            if selection_to_prune.contains(&Selector::AST) {
+        self.prune(selection_to_prune);
+
+        serde_json::to_writer(std::io::stdout(), &self).expect("Stdout writing error");
+        std::process::exit(era_compiler_common::EXIT_CODE_SUCCESS);
+    }
+
+    ///
+    /// Removes the artifacts that were not requested by the user, and empty contracts.
+    ///
+    pub fn prune(&mut self, selection_to_prune: Selection) {
+        for (path, source) in self.sources.iter_mut() {
+            if selection_to_prune.is_pruned(path, &Selector::AST) {
                 source.ast = None;
             }
         }
 
         let contracts = self
             .contracts
-            .values_mut()
-            .flat_map(|contracts| contracts.values_mut())
-            .collect::<Vec<&mut Contract>>();
-        for contract in contracts.into_iter() {
-            if selection_to_prune.contains(&Selector::Metadata) {
+            .iter_mut()
+            .flat_map(|(path, contracts)| {
+                contracts
+                    .values_mut()
+                    .map(move |contract| (path.as_str(), contract))
+            })
+            .collect::<Vec<(&str, &mut Contract)>>();
+        for (path, contract) in contracts.into_iter() {
+            if selection_to_prune.is_pruned(path, &Selector::Metadata) {
                 contract.metadata = serde_json::Value::Null;
             }
-            if selection_to_prune.contains(&Selector::Yul) {
+            if selection_to_prune.is_pruned(path, &Selector::Yul) {
                 contract.ir_optimized = String::new();
             }
             if let Some(ref mut evm) = contract.evm {
-                if selection_to_prune.contains(&Selector::EVMLA) {
+                if selection_to_prune.is_pruned(path, &Selector::EVMLA) {
                     evm.legacy_assembly = serde_json::Value::Null;
                 }
-                if selection_to_prune.contains(&Selector::MethodIdentifiers) {
+                if selection_to_prune.is_pruned(path, &Selector::MethodIdentifiers) {
                     evm.method_identifiers.clear();
                 }
                 evm.extra_metadata = None;
@@ -132,9 +154,6 @@ impl Output {
             contracts.retain(|_, contract| !contract.is_empty());
             !contracts.is_empty()
         });
-
-        serde_json::to_writer(std::io::stdout(), &self).expect("Stdout writing error");
-        std::process::exit(era_compiler_common::EXIT_CODE_SUCCESS);
     }
 
     ///
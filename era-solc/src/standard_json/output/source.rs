@@ -35,6 +35,13 @@ impl Source {
         Self { id, ast: None }
     }
 
+    ///
+    /// Returns the source unit name from the AST `absolutePath` field.
+    ///
+    pub fn absolute_path(&self) -> Option<&str> {
+        self.ast.as_ref()?.get("absolutePath")?.as_str()
+    }
+
     ///
     /// Checks the AST node for the usage of `<address payable>`'s `send` and `transfer` methods.
     ///
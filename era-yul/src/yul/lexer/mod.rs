@@ -30,6 +30,8 @@ pub struct Lexer {
     location: Location,
     /// The peeked lexeme, waiting to be fetched.
     peeked: Option<Token>,
+    /// The skipped comments, with the locations of the code following them.
+    comments: Vec<(Location, String)>,
 }
 
 impl Lexer {
@@ -44,6 +46,7 @@ impl Lexer {
             offset: 0,
             location: Location::default(),
             peeked: None,
+            comments: Vec::new(),
         }
     }
 
@@ -70,9 +73,11 @@ impl Lexer {
             }
 
             if let Some(token) = Comment::parse(input) {
+                let comment = input.get(..token.length).unwrap_or(input).to_owned();
                 self.offset += token.length;
                 self.location
                     .shift_down(token.location.line, token.location.column);
+                self.comments.push((self.location, comment));
                 continue;
             }
 
@@ -120,6 +125,15 @@ impl Lexer {
         Ok(Token::new(self.location, Lexeme::EndOfFile, 0))
     }
 
+    ///
+    /// Returns the comments skipped so far, with the locations of the code following them.
+    ///
+    /// Comments may carry annotations, such as the `@src` source locations emitted by `solc`.
+    ///
+    pub fn comments(&self) -> &[(Location, String)] {
+        self.comments.as_slice()
+    }
+
     ///
     /// Peeks the next lexeme without advancing the iterator.
     ///
@@ -89,3 +89,19 @@ object "Test" {
         }
     }
 }
+
+#[test]
+fn comments() {
+    let input = r#"{
+    /// @src 0:10:20
+    mstore(64, 128)
+}"#;
+
+    let mut lexer = Lexer::new(input.to_owned());
+    while lexer.next().expect("Always valid").lexeme != Lexeme::EndOfFile {}
+
+    assert_eq!(
+        lexer.comments(),
+        &[(Location::new(3, 1), "/// @src 0:10:20\n".to_owned())]
+    );
+}
@@ -2,8 +2,6 @@
 //! The assignment expression statement.
 //!
 
-use std::collections::BTreeSet;
-
 use crate::yul::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
@@ -105,9 +103,9 @@ impl Assignment {
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
         self.initializer.get_missing_libraries()
     }
 
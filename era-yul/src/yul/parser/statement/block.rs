@@ -2,8 +2,6 @@
 //! The source code block.
 //!
 
-use std::collections::BTreeSet;
-
 use crate::yul::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
@@ -131,10 +129,10 @@ where
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
-        let mut libraries = BTreeSet::new();
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
+        let mut libraries = Vec::new();
         for statement in self.statements.iter() {
             libraries.extend(statement.get_missing_libraries());
         }
@@ -2,8 +2,6 @@
 //! The Yul code.
 //!
 
-use std::collections::BTreeSet;
-
 use crate::yul::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
@@ -62,9 +60,9 @@ where
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
         self.block.get_missing_libraries()
     }
 
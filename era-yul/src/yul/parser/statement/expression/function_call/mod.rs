@@ -4,8 +4,6 @@
 
 pub mod name;
 
-use std::collections::BTreeSet;
-
 use crate::yul::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
@@ -95,10 +93,10 @@ impl FunctionCall {
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
-        let mut libraries = BTreeSet::new();
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
+        let mut libraries = Vec::new();
 
         if let Name::LinkerSymbol = self.name {
             if let Expression::Literal(Literal {
@@ -106,7 +104,7 @@ impl FunctionCall {
                 ..
             }) = self.arguments.first().expect("Always exists")
             {
-                libraries.insert(library_path.to_string());
+                libraries.push((library_path.to_string(), self.location));
             }
             return libraries;
         }
@@ -5,8 +5,6 @@
 pub mod function_call;
 pub mod literal;
 
-use std::collections::BTreeSet;
-
 use crate::yul::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
@@ -80,13 +78,13 @@ impl Expression {
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
         match self {
             Self::FunctionCall(inner) => inner.get_missing_libraries(),
-            Self::Identifier(_) => BTreeSet::new(),
-            Self::Literal(_) => BTreeSet::new(),
+            Self::Identifier(_) => Vec::new(),
+            Self::Literal(_) => Vec::new(),
         }
     }
 
@@ -2,8 +2,6 @@
 //! The for-loop statement.
 //!
 
-use std::collections::BTreeSet;
-
 use crate::yul::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::location::Location;
@@ -63,9 +61,9 @@ where
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
         let mut libraries = self.initializer.get_missing_libraries();
         libraries.extend(self.condition.get_missing_libraries());
         libraries.extend(self.finalizer.get_missing_libraries());
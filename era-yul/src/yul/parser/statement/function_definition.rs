@@ -162,9 +162,9 @@ where
     }
 
     ///
-    /// Gets the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
         self.body.get_missing_libraries()
     }
 
@@ -2,8 +2,6 @@
 //! The if-conditional statement.
 //!
 
-use std::collections::BTreeSet;
-
 use crate::yul::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::location::Location;
@@ -53,9 +51,9 @@ where
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
         let mut libraries = self.condition.get_missing_libraries();
         libraries.extend(self.block.get_missing_libraries());
         libraries
@@ -13,8 +13,6 @@ pub mod object;
 pub mod switch;
 pub mod variable_declaration;
 
-use std::collections::BTreeSet;
-
 use crate::yul::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
@@ -153,9 +151,9 @@ where
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
         match self {
             Self::Object(inner) => inner.get_missing_libraries(),
             Self::Code(inner) => inner.get_missing_libraries(),
@@ -167,9 +165,9 @@ where
             Self::IfConditional(inner) => inner.get_missing_libraries(),
             Self::Switch(inner) => inner.get_missing_libraries(),
             Self::ForLoop(inner) => inner.get_missing_libraries(),
-            Self::Continue(_) => BTreeSet::new(),
-            Self::Break(_) => BTreeSet::new(),
-            Self::Leave(_) => BTreeSet::new(),
+            Self::Continue(_) => Vec::new(),
+            Self::Break(_) => Vec::new(),
+            Self::Leave(_) => Vec::new(),
         }
     }
 
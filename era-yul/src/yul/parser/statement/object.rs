@@ -2,7 +2,6 @@
 //! The Yul object.
 //!
 
-use std::collections::BTreeSet;
 use std::collections::HashSet;
 
 use crate::yul::dependencies::Dependencies;
@@ -179,9 +178,9 @@ where
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
         let mut missing_libraries = self.code.get_missing_libraries();
         if let Some(inner_object) = &self.inner_object {
             missing_libraries.extend(inner_object.get_missing_libraries());
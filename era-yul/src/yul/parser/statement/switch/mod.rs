@@ -4,8 +4,6 @@
 
 pub mod case;
 
-use std::collections::BTreeSet;
-
 use crate::yul::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
@@ -120,10 +118,10 @@ where
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
-        let mut libraries = BTreeSet::new();
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
+        let mut libraries = Vec::new();
         for case in self.cases.iter() {
             libraries.extend(case.get_missing_libraries());
         }
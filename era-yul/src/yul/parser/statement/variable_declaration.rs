@@ -2,8 +2,6 @@
 //! The variable declaration statement.
 //!
 
-use std::collections::BTreeSet;
-
 use crate::yul::dependencies::Dependencies;
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
@@ -84,14 +82,12 @@ impl VariableDeclaration {
     }
 
     ///
-    /// Get the list of missing deployable libraries.
+    /// Get the missing deployable libraries with locations of all their `linkersymbol` calls.
     ///
-    pub fn get_missing_libraries(&self) -> BTreeSet<String> {
+    pub fn get_missing_libraries(&self) -> Vec<(String, Location)> {
         self.expression
             .as_ref()
-            .map_or_else(BTreeSet::new, |expression| {
-                expression.get_missing_libraries()
-            })
+            .map_or_else(Vec::new, |expression| expression.get_missing_libraries())
     }
 
     ///